@@ -0,0 +1,77 @@
+//! Steps through the crane moves of 2022 day 5, drawing the stacks after
+//! every move.
+//!
+//! ```text
+//! cargo run --example crates -- --crane 9001 --step
+//! ```
+
+use advent_of_code::input::Inputs;
+use advent_of_code::year2022::day5::{self, Crane};
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+struct Cli {
+    /// Crane model moving the crates
+    #[arg(long, value_enum, default_value_t = CraneModel::CrateMover9000)]
+    crane: CraneModel,
+
+    /// Wait for enter before each move
+    #[arg(long)]
+    step: bool,
+
+    /// Read the input from <DIR>/2022/day5.txt instead of the repository's
+    /// input folder.  Can also be set with AOC_INPUT.
+    #[arg(long, value_name = "DIR")]
+    input: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum CraneModel {
+    /// Moves one crate at a time, part 1
+    #[value(name = "9000")]
+    CrateMover9000,
+
+    /// Moves several crates at once, part 2
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let crane = match cli.crane {
+        CraneModel::CrateMover9000 => Crane::CrateMover9000,
+        CraneModel::CrateMover9001 => Crane::CrateMover9001,
+    };
+    let parsed = Inputs::resolve(cli.input)
+        .read(2022, 5)
+        .map_err(|e| e.to_string())
+        .and_then(|x| day5::generator(&x).map_err(|e| e.in_day(2022, 5).to_string()));
+    let day = match parsed {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}\n", day.state);
+    let stdin = std::io::stdin();
+    let end = day.replay(crane, |operation, state| {
+        if cli.step {
+            let _ = stdin.read_line(&mut String::new());
+        }
+        println!("{}\n{}\n", operation, state);
+    });
+    match end.and_then(|x| x.tops()) {
+        Ok(tops) => {
+            println!("Top crates: {}", tops);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e.in_day(2022, 5));
+            ExitCode::FAILURE
+        }
+    }
+}
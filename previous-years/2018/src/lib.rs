@@ -1,6 +0,0 @@
-use aoc_runner_derive::aoc_lib;
-
-pub mod day1;
-pub mod day2;
-
-aoc_lib!{ year = 2018 }
@@ -1,4 +0,0 @@
-use advent_of_code_2018;
-use aoc_runner_derive::aoc_main;
-
-aoc_main! { lib = advent_of_code_2018 }
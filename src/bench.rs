@@ -0,0 +1,196 @@
+use crate::error::Result;
+use crate::registry::{DayPart, Part};
+use std::fmt::{Display, Write};
+use std::time::{Duration, Instant};
+
+/// Which half of a solution a measurement covers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Generator,
+    Solver,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Generator => f.pad("generator"),
+            Self::Solver => f.pad("runner"),
+        }
+    }
+}
+
+/// Summary of repeated timings of one stage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises a set of samples, percentiles use the nearest rank.
+    /// Panics if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort();
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Self {
+            iterations: samples.len(),
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    pub dp: DayPart,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Times the generator and the solver of a solution separately, each over
+/// `iterations` runs.  The solver is timed against a single parsed input so
+/// the generator cost does not leak into it.
+pub fn measure(
+    dp: DayPart,
+    solution: &Part,
+    input: &str,
+    iterations: usize,
+) -> Result<[Measurement; 2]> {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut parsed = None;
+    for _ in 0..iterations.max(1) {
+        let start_time = Instant::now();
+        let x = solution.generate(input)?;
+        samples.push(start_time.elapsed());
+        parsed = Some(x);
+    }
+    let generator = Stats::from_samples(samples);
+
+    let parsed = parsed.unwrap();
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        let start_time = Instant::now();
+        solution.solve(&*parsed)?;
+        samples.push(start_time.elapsed());
+    }
+    let solver = Stats::from_samples(samples);
+
+    Ok([
+        Measurement {
+            dp,
+            stage: Stage::Generator,
+            stats: generator,
+        },
+        Measurement {
+            dp,
+            stage: Stage::Solver,
+            stats: solver,
+        },
+    ])
+}
+
+/// Renders measurements as CSV with a header row, durations in nanoseconds.
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut out = String::from("year,day,part,stage,iterations,min_ns,median_ns,p95_ns\n");
+    for m in measurements {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            m.dp.year,
+            m.dp.day,
+            m.dp.part,
+            m.stage,
+            m.stats.iterations,
+            m.stats.min.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.p95.as_nanos()
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Renders measurements as a JSON array of objects, one per line, with the
+/// same fields as the CSV.
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let rows: Vec<String> = measurements
+        .iter()
+        .map(|m| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"stage\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+                m.dp.year,
+                m.dp.day,
+                m.dp.part,
+                m.stage,
+                m.stats.iterations,
+                m.stats.min.as_nanos(),
+                m.stats.median.as_nanos(),
+                m.stats.p95.as_nanos()
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::Registry;
+
+    fn ms(x: u64) -> Duration {
+        Duration::from_millis(x)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples((1..=100).rev().map(ms).collect());
+        assert_eq!(stats.iterations, 100);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(50));
+        assert_eq!(stats.p95, ms(95));
+
+        let stats = Stats::from_samples(vec![ms(7)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(7), ms(7), ms(7)));
+    }
+
+    #[test]
+    fn test_measure_and_render() {
+        let mut registry = Registry::new();
+        registry.day(2022, 6).part1(str::to_owned, str::len);
+        let dp = DayPart {
+            year: 2022,
+            day: 6,
+            part: 1,
+        };
+        let measurements = measure(dp, registry.get(&dp).unwrap(), "abc", 5).unwrap();
+        assert_eq!(measurements[0].stage, Stage::Generator);
+        assert_eq!(measurements[1].stats.iterations, 5);
+
+        let fixed = Stats {
+            iterations: 5,
+            min: Duration::from_nanos(1),
+            median: Duration::from_nanos(2),
+            p95: Duration::from_nanos(3),
+        };
+        let measurements = measurements.map(|m| Measurement { stats: fixed, ..m });
+        assert_eq!(
+            to_csv(&measurements),
+            "year,day,part,stage,iterations,min_ns,median_ns,p95_ns
+2022,6,1,generator,5,1,2,3
+2022,6,1,runner,5,1,2,3
+"
+        );
+        assert_eq!(
+            to_json(&measurements),
+            r#"[
+  {"year": 2022, "day": 6, "part": 1, "stage": "generator", "iterations": 5, "min_ns": 1, "median_ns": 2, "p95_ns": 3},
+  {"year": 2022, "day": 6, "part": 1, "stage": "runner", "iterations": 5, "min_ns": 1, "median_ns": 2, "p95_ns": 3}
+]
+"#
+        );
+    }
+}
@@ -0,0 +1,173 @@
+/// A picture of lit and unlit pixels, for puzzles whose answer is drawn on a
+/// screen rather than printed.  Solvers can return one directly, the runner
+/// shows the picture and submits whatever letters [`Bitmap::ocr`] reads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+/// Height of the letters Advent of Code draws with.
+pub const LETTER_HEIGHT: usize = 6;
+
+// Letters are 4 pixels wide and drawn 5 pixels apart.
+const LETTER_WIDTH: usize = 4;
+const LETTER_PITCH: usize = 5;
+
+// The letters known to show up in puzzles, as drawn by the puzzles.
+const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+const LIT: char = '█';
+const UNLIT: char = '░';
+
+impl Bitmap {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    /// Builds a bitmap from rows of pixels.  Panics if the rows are not all
+    /// the same length.
+    pub fn from_rows<R: IntoIterator<Item = bool>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut pixels = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let len = pixels.len();
+            pixels.extend(row);
+            let row_width = pixels.len() - len;
+            assert_eq!(*width.get_or_insert(row_width), row_width, "ragged bitmap");
+            height += 1;
+        }
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a pixel is lit, out of bounds pixels are not.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.pixels[y * self.width + x] = lit;
+    }
+
+    /// Reads the letters drawn on the bitmap.  Returns `None` unless every
+    /// letter is one the font knows, a partly read answer is no use to
+    /// submit.
+    pub fn ocr(&self) -> Option<String> {
+        if self.height != LETTER_HEIGHT || self.width == 0 {
+            return None;
+        }
+
+        (0..self.width.div_ceil(LETTER_PITCH))
+            .map(|letter| {
+                let left = letter * LETTER_PITCH;
+                let glyph: String = (0..LETTER_HEIGHT)
+                    .flat_map(|y| (left..left + LETTER_WIDTH).map(move |x| (x, y)))
+                    .map(|(x, y)| if self.get(x, y) { '#' } else { '.' })
+                    .collect();
+                let gap_is_clear = (0..LETTER_HEIGHT).all(|y| !self.get(left + LETTER_WIDTH, y));
+                FONT.iter()
+                    .find(|(_, pattern)| *pattern == glyph)
+                    .filter(|_| gap_is_clear)
+                    .map(|(c, _)| *c)
+            })
+            .collect()
+    }
+
+    /// Draws the bitmap with block characters, one line per row.
+    pub fn render(&self) -> String {
+        self.pixels
+            .chunks(self.width.max(1))
+            .map(|row| {
+                row.iter()
+                    .map(|&x| if x { LIT } else { UNLIT })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(picture: &str) -> Bitmap {
+        Bitmap::from_rows(picture.lines().map(|line| line.chars().map(|c| c == '#')))
+    }
+
+    #[test]
+    fn test_ocr() {
+        let bitmap = parse(
+            "###..#..#.###....##.###..###..#.....##..
+#..#.#.#..#..#....#.#..#.#..#.#....#..#.
+#..#.##...#..#....#.###..#..#.#....#..#.
+###..#.#..###.....#.#..#.###..#....####.
+#.#..#.#..#....#..#.#..#.#....#....#..#.
+#..#.#..#.#.....##..###..#....####.#..#.",
+        );
+        assert_eq!(bitmap.ocr().as_deref(), Some("RKPJBPLA"));
+
+        for (c, pattern) in FONT {
+            let bitmap = Bitmap::from_rows(
+                pattern
+                    .as_bytes()
+                    .chunks(LETTER_WIDTH)
+                    .map(|row| row.iter().map(|&x| x == b'#')),
+            );
+            assert_eq!(bitmap.ocr(), Some(c.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_ocr_unknown() {
+        let mut bitmap = parse("####\n#...\n###.\n#...\n#...\n####");
+        assert_eq!(bitmap.ocr().as_deref(), Some("E"));
+        bitmap.set(3, 5, false);
+        assert_eq!(bitmap.ocr(), None);
+        assert_eq!(Bitmap::new(40, 7).ocr(), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let bitmap = parse("#.\n.#");
+        assert_eq!(bitmap.render(), "█░\n░█");
+        assert!(bitmap.get(0, 0) && !bitmap.get(1, 0) && !bitmap.get(5, 5));
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence starts repeating itself.  Steps `start` onwards repeat
+/// every `length` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// The state a simulation reached, and the cycle it used to get there if
+/// it found one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run<S> {
+    pub state: S,
+    pub cycle: Option<Cycle>,
+}
+
+/// Steps `initial` forward `n` times.  Every state is remembered, so as
+/// soon as one comes back the rest of the way is worked out from the cycle
+/// instead of being simulated, which makes a billion steps as cheap as the
+/// cycle is long.
+pub fn simulate<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    n: usize,
+) -> Run<S> {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+    for idx in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: idx - start,
+            };
+            return Run {
+                state: history.swap_remove(cycle.reduce(n)),
+                cycle: Some(cycle),
+            };
+        }
+
+        seen.insert(state.clone(), idx);
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+
+    Run { state, cycle: None }
+}
+
+/// Finds the cycle of a sequence with Brent's algorithm, which only keeps
+/// two states around and needs nothing but `Eq` from them.  Never returns
+/// if the sequence does not repeat.
+pub fn find_cycle<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length first, the tortoise waits at powers of two for the
+    // hare to come around to it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a cycle ahead, the two meet where the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The first value an iterator produces twice.  The cycle starts where it
+/// was first seen and is as long as the gap until it came back.
+pub fn first_repeat<T: Clone + Eq + Hash>(
+    values: impl IntoIterator<Item = T>,
+) -> Option<(Cycle, T)> {
+    let mut seen = HashMap::new();
+    for (idx, value) in values.into_iter().enumerate() {
+        if let Some(&start) = seen.get(&value) {
+            let cycle = Cycle {
+                start,
+                length: idx - start,
+            };
+            return Some((cycle, value));
+        }
+        seen.insert(value, idx);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1, ...
+    fn collatz(x: &u64) -> u64 {
+        match x {
+            x if x % 2 == 0 => x / 2,
+            x => 3 * x + 1,
+        }
+    }
+
+    #[test]
+    fn test_simulate() {
+        let run = simulate(3, collatz, 1_000_000_000);
+        assert_eq!(
+            run.cycle,
+            Some(Cycle {
+                start: 5,
+                length: 3
+            })
+        );
+        // Step 1e9 lands on the same spot in the cycle as step 5 + 2.
+        assert_eq!(run.state, 1);
+
+        let run = simulate(3, collatz, 4);
+        assert_eq!(
+            run,
+            Run {
+                state: 8,
+                cycle: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(3, collatz);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 5,
+                length: 3
+            }
+        );
+        assert_eq!(cycle.reduce(3), 3);
+        assert_eq!(cycle.reduce(9), 6);
+    }
+
+    #[test]
+    fn test_first_repeat() {
+        let (cycle, value) = first_repeat([1, 5, 7, 5, 1]).unwrap();
+        assert_eq!(value, 5);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 1,
+                length: 2
+            }
+        );
+        assert_eq!(first_repeat([1, 2, 3]), None);
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt::Display;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Something wrong with a puzzle input, or a solver that could not come up
+/// with an answer.  Generators point at the offending text with
+/// [`Error::at`], the registry fills in which puzzle it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub message: String,
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    pub position: Option<Position>,
+}
+
+/// Where in the input an error was found.  Lines and columns count from 1,
+/// columns in characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+
+    // Number of characters the error covers, at least 1.
+    pub width: usize,
+
+    // The full line the error was found on.
+    pub text: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            year: None,
+            day: None,
+            position: None,
+        }
+    }
+
+    /// An error about `offending`, which must be a slice of `input`.  The
+    /// line and column are worked out from where the slice sits, so
+    /// generators can hand over whatever piece of text they choked on.  A
+    /// slice from somewhere else produces an error without a position.
+    pub fn at(input: &str, offending: &str, message: impl Into<String>) -> Self {
+        let start = (offending.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        let mut error = Self::new(message);
+        if start <= input.len() && start + offending.len() <= input.len() {
+            error.position = Some(Position::new(input, start, offending.len()));
+        }
+        error
+    }
+
+    /// An error about the end of the input, for when it stops early.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Records which puzzle the error came from.
+    pub fn in_day(mut self, year: u32, day: u32) -> Self {
+        self.year = Some(year);
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Position {
+    fn new(input: &str, start: usize, len: usize) -> Self {
+        let line_start = input[..start].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let line_end = input[start..]
+            .find('\n')
+            .map(|x| x + start)
+            .unwrap_or(input.len());
+        let text = &input[line_start..line_end];
+        let end = (start + len).min(line_end);
+        Self {
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            width: input[start..end].chars().count().max(1),
+            text: text.to_owned(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Some(year), Some(day)) = (self.year, self.day) {
+            write!(f, "{} day {}, ", year, day)?;
+        }
+        let position = match &self.position {
+            Some(x) => x,
+            None => return write!(f, "{}", self.message),
+        };
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            position.line, position.column, self.message
+        )?;
+
+        // Control characters are escaped so a stray carriage return or tab
+        // shows up in the excerpt instead of mangling it.
+        let gutter = position.line.to_string().len();
+        let escaped = |s: &str| s.chars().flat_map(char::escape_debug).collect::<String>();
+        let prefix = position
+            .text
+            .chars()
+            .take(position.column - 1)
+            .collect::<String>();
+        let marked = position
+            .text
+            .chars()
+            .skip(position.column - 1)
+            .take(position.width)
+            .collect::<String>();
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", position.line, escaped(&position.text))?;
+        write!(
+            f,
+            "{:gutter$} | {:pad$}{}",
+            "",
+            "",
+            "^".repeat(escaped(&marked).chars().count().max(1)),
+            pad = escaped(&prefix).chars().count()
+        )?;
+
+        if position.text.ends_with('\r') {
+            write!(
+                f,
+                "\n{:gutter$} = note: the line ends in a carriage return, the input may have CRLF line endings",
+                ""
+            )?;
+        } else if position.text.is_empty() || position.column > position.text.chars().count() {
+            write!(
+                f,
+                "\n{:gutter$} = note: ran out of input here, the file may be truncated",
+                ""
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "1000\n2000\n3x00\n";
+
+    #[test]
+    fn test_at() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let error = Error::at(INPUT, &line[1..2], "expected a digit");
+        let position = error.position.unwrap();
+        assert_eq!(position.line, 3);
+        assert_eq!(position.column, 2);
+        assert_eq!(position.width, 1);
+        assert_eq!(position.text, "3x00");
+    }
+
+    #[test]
+    fn test_at_foreign_slice() {
+        let error = Error::at(INPUT, "3x00", "expected a number");
+        assert_eq!(error.position, None);
+        assert_eq!(error.to_string(), "expected a number");
+    }
+
+    #[test]
+    fn test_render() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let error = Error::at(INPUT, line, "expected a number").in_day(2022, 1);
+        assert_eq!(
+            error.to_string(),
+            "2022 day 1, line 3, column 1: expected a number
+  |
+3 | 3x00
+  | ^^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_crlf() {
+        let input = "1000\r\n2000\r\n";
+        let line = input.split('\n').next().unwrap();
+        let error = Error::at(input, line, "expected a number");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a number
+  |
+1 | 1000\\r
+  | ^^^^^^
+  = note: the line ends in a carriage return, the input may have CRLF line endings"
+        );
+    }
+
+    #[test]
+    fn test_render_truncated() {
+        let input = "move 1 from 2 to 1\nmove 3 from";
+        let error = Error::at_end(input, "expected a stack number");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 12: expected a stack number
+  |
+2 | move 3 from
+  |            ^
+  = note: ran out of input here, the file may be truncated"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on the plane, or the offset between two points.  `y` grows
+/// upwards, so [`Direction::Up`] adds one to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Distance walking only along the axes.
+    pub fn manhattan(&self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when diagonal steps count as one, like a king in chess.
+    pub fn chebyshev(&self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Shrinks each coordinate to -1, 0 or 1, turning an offset into the
+    /// single step that heads the same way.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The four points sharing an edge with this one.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d.vector())
+    }
+
+    /// The eight points sharing an edge or a corner with this one.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(|d| d.vector())
+            .chain(Diagonal::ALL.into_iter().map(|d| d.vector()))
+            .map(move |v| self + v)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The four cardinal directions, listed clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Reads a direction written as `U`/`D`/`L`/`R` or as an arrow.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Self::Up),
+            'R' | '>' => Some(Self::Right),
+            'D' | 'v' => Some(Self::Down),
+            'L' | '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// A single step in this direction.
+    pub fn vector(&self) -> Point {
+        match self {
+            Self::Up => Point::new(0, 1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, -1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+/// The four diagonal directions, listed clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Diagonal {
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+impl Diagonal {
+    pub const ALL: [Diagonal; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    /// A single step in this direction.
+    pub fn vector(&self) -> Point {
+        match self {
+            Self::UpRight => Point::new(1, 1),
+            Self::DownRight => Point::new(1, -1),
+            Self::DownLeft => Point::new(-1, -1),
+            Self::UpLeft => Point::new(-1, 1),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+/// A line segment, both ends included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether the line is horizontal or vertical.
+    pub fn is_cardinal(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    /// Whether the line runs at exactly 45 degrees.
+    pub fn is_diagonal(&self) -> bool {
+        let delta = self.end - self.start;
+        delta.x != 0 && delta.x.abs() == delta.y.abs()
+    }
+
+    /// The points the line passes through, from start to end, using
+    /// Bresenham's algorithm.  Cardinal and 45 degree lines hit exactly the
+    /// points on them, steeper or shallower ones the closest points.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let end = self.end;
+        let step = (end - self.start).signum();
+        let dx = (end.x - self.start.x).abs();
+        let dy = -(end.y - self.start.y).abs();
+        let mut error = dx + dy;
+        let mut current = Some(self.start);
+        std::iter::from_fn(move || {
+            let point = current?;
+            if point == end {
+                current = None;
+                return Some(point);
+            }
+
+            let mut next = point;
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                next.x += step.x;
+            }
+            if doubled <= dx {
+                error += dx;
+                next.y += step.y;
+            }
+            current = Some(next);
+            Some(point)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a + b, Point::new(-2, 7));
+        assert_eq!(a - b, Point::new(4, -3));
+        assert_eq!(-a * 2, Point::new(-2, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+        assert_eq!(Point::ORIGIN.neighbors4().count(), 4);
+        assert!(Point::ORIGIN
+            .neighbors8()
+            .all(|p| p.chebyshev(Point::ORIGIN) == 1));
+        assert_eq!(a.to_string(), "(1, 2)");
+    }
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Diagonal::UpLeft.turn_right(), Diagonal::UpRight);
+        assert_eq!(Diagonal::UpRight.reverse(), Diagonal::DownLeft);
+        for d in Direction::ALL {
+            assert_eq!(d.reverse().vector(), -d.vector());
+            assert_eq!(d.turn_left().turn_right(), d);
+        }
+    }
+
+    #[test]
+    fn test_line() {
+        let points = |a: (i64, i64), b: (i64, i64)| {
+            Line::new(Point::new(a.0, a.1), Point::new(b.0, b.1))
+                .points()
+                .map(|p| (p.x, p.y))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(points((0, 9), (2, 9)), [(0, 9), (1, 9), (2, 9)]);
+        assert_eq!(points((7, 2), (7, 0)), [(7, 2), (7, 1), (7, 0)]);
+        assert_eq!(points((8, 0), (6, 2)), [(8, 0), (7, 1), (6, 2)]);
+        assert_eq!(points((3, 3), (3, 3)), [(3, 3)]);
+        assert_eq!(
+            points((0, 0), (4, 2)),
+            [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+
+        let line = Line::new(Point::new(5, 5), Point::new(8, 2));
+        assert!(line.is_diagonal() && !line.is_cardinal());
+    }
+}
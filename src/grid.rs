@@ -0,0 +1,351 @@
+use crate::error::{Error, Result};
+use crate::geometry::Direction;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells stored row by row.  Positions are
+/// `(row, column)` pairs counted from the top left corner, so walking
+/// [`Direction::Up`] goes towards row 0.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// Row and column offsets of the eight cells around a cell.
+const DELTAS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from rows of cells.  Returns `None` if the rows are not
+    /// all the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Option<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+            if *width.get_or_insert(cells.len() - len) != cells.len() - len {
+                return None;
+            }
+            height += 1;
+        }
+        Some(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a character map, one row per line, turning each character
+    /// into a cell with `cell`.  Characters `cell` rejects and rows of the
+    /// wrong length are reported where they sit in `input`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let line = line.trim_end_matches('\r');
+            for (idx, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::at(
+                        input,
+                        &line[idx..idx + c.len_utf8()],
+                        format!("unexpected {:?} in the map", c),
+                    )
+                })?;
+                cells.push(value);
+            }
+
+            let row_width = line.chars().count();
+            let expected = *width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(Error::at(
+                    input,
+                    line,
+                    format!(
+                        "expected {} cells like the first row, found {}",
+                        expected, row_width
+                    ),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.contains(row, col)
+            .then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.contains(row, col)
+            .then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// Iterates every cell, row by row.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Iterates a column top to bottom, reverse it to go bottom to top.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column out of bounds");
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// The position one step away in `direction`, if it is on the grid.
+    pub fn step(&self, row: usize, col: usize, direction: Direction) -> Option<(usize, usize)> {
+        let step = direction.vector();
+        self.offset(row, col, (-step.y as isize, step.x as isize))
+    }
+
+    fn offset(&self, row: usize, col: usize, (dr, dc): (isize, isize)) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+        self.contains(row, col).then_some((row, col))
+    }
+
+    /// Walks from a cell to the edge of the grid, not including the cell
+    /// itself.  This is the line of sight from the cell in `direction`.
+    pub fn walk(
+        &self,
+        row: usize,
+        col: usize,
+        direction: Direction,
+    ) -> impl Iterator<Item = &T> + '_ {
+        let mut position = Some((row, col));
+        std::iter::from_fn(move || {
+            let (row, col) = position?;
+            position = self.step(row, col, direction);
+            position.map(|(row, col)| &self[(row, col)])
+        })
+    }
+
+    /// The up to four cells sharing an edge with a cell.
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(row, col, d))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with a cell.
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DELTAS_8
+            .into_iter()
+            .filter_map(move |delta| self.offset(row, col, delta))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| (col, row))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| {
+            (self.height - 1 - col, row)
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |row, col| {
+            (col, self.width - 1 - row)
+        })
+    }
+
+    // Builds a grid of the given size, taking each cell from the position
+    // `source` picks in this one.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| self[source(row, col)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                row, col, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(row, col).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                row, col, width, height
+            )
+        })
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\r\n456\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get(2, 0), None);
+
+        let error = Grid::parse("123\n4x6", |c| c.to_digit(10)).unwrap_err();
+        let position = error.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 2));
+
+        let error = Grid::parse("123\n45", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.message,
+            "expected 3 cells like the first row, found 2"
+        );
+    }
+
+    #[test]
+    fn test_walks() {
+        let grid = chars("abc\ndef\nghi");
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).rev().collect::<String>(), "heb");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.walk(1, 1, Direction::Up).collect::<String>(), "b");
+        assert_eq!(grid.walk(1, 0, Direction::Right).collect::<String>(), "ef");
+        assert_eq!(grid.walk(2, 2, Direction::Left).collect::<String>(), "hg");
+        assert_eq!(grid.walk(2, 2, Direction::Down).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = chars("abc\ndef\nghi");
+        let around =
+            |it: &mut dyn Iterator<Item = (usize, usize)>| it.map(|x| grid[x]).collect::<String>();
+        assert_eq!(around(&mut grid.neighbors4(0, 0)), "bd");
+        assert_eq!(around(&mut grid.neighbors4(1, 1)), "bfhd");
+        assert_eq!(around(&mut grid.neighbors8(1, 1)), "abcdfghi");
+        assert_eq!(around(&mut grid.neighbors8(2, 0)), "deh");
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
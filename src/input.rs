@@ -0,0 +1,161 @@
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing at an alternate input root.
+pub const INPUT_ROOT_ENV: &str = "AOC_INPUT";
+
+/// Locates puzzle inputs laid out as `<root>/<year>/day<N>.txt`, the same
+/// layout the python `Year` class reads.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    root: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Picks the input root from the command line flag, then the
+    /// `AOC_INPUT` environment variable, then the `input/` folder of this
+    /// repository.
+    pub fn resolve(flag: Option<PathBuf>) -> Self {
+        Self::new(pick_root(flag, std::env::var_os(INPUT_ROOT_ENV)))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    /// Where the `n`th example block of a day is kept, counting from 1.
+    pub fn example_path(&self, year: u32, day: u32, n: usize) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{}.example{}.txt", day, n))
+    }
+
+    /// Where the number of example blocks a day's page had is noted once
+    /// the page has been fetched, so pages without any are not fetched
+    /// again.
+    pub fn example_count_path(&self, year: u32, day: u32) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{}.examples.txt", day))
+    }
+
+    /// Reads the input for a day.  Trailing newlines are trimmed so
+    /// generators see the same text aoc-runner used to hand them.
+    pub fn read(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(mut text) => {
+                let len = text.trim_end_matches('\n').len();
+                text.truncate(len);
+                Ok(text)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(InputError::Missing { year, day, path })
+            }
+            Err(source) => Err(InputError::Unreadable { path, source }),
+        }
+    }
+}
+
+fn pick_root(flag: Option<PathBuf>, env: Option<OsString>) -> PathBuf {
+    flag.or_else(|| env.filter(|x| !x.is_empty()).map(PathBuf::from))
+        .unwrap_or_else(default_root)
+}
+
+/// The `input/` folder of this repository.  Tests that check the real
+/// inputs read from here, whatever `AOC_INPUT` says.
+pub(crate) fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { year: u32, day: u32, path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { year, day, path } => write!(
+                f,
+                "no input for {} day {}, expected it at {}",
+                year,
+                day,
+                path.display()
+            ),
+            Self::Unreadable { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Missing { .. } => None,
+            Self::Unreadable { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let inputs = Inputs::new("some/root");
+        assert_eq!(inputs.path(2022, 7), Path::new("some/root/2022/day7.txt"));
+        assert_eq!(
+            inputs.example_path(2022, 7, 2),
+            Path::new("some/root/2022/day7.example2.txt")
+        );
+        assert_eq!(
+            inputs.example_count_path(2022, 7),
+            Path::new("some/root/2022/day7.examples.txt")
+        );
+    }
+
+    #[test]
+    fn test_pick_root() {
+        let flag = Some(PathBuf::from("flag"));
+        let env = Some(OsString::from("env"));
+        assert_eq!(pick_root(flag.clone(), env.clone()), PathBuf::from("flag"));
+        assert_eq!(pick_root(None, env), PathBuf::from("env"));
+        assert!(pick_root(None, Some(OsString::new())).ends_with("input"));
+        assert!(pick_root(None, None).ends_with("input"));
+    }
+
+    #[test]
+    fn test_missing() {
+        let inputs = Inputs::new("does/not/exist");
+        let err = inputs.read(2022, 7).unwrap_err();
+        assert!(matches!(err, InputError::Missing { .. }));
+        assert_eq!(
+            err.to_string(),
+            "no input for 2022 day 7, expected it at does/not/exist/2022/day7.txt"
+        );
+    }
+
+    #[test]
+    fn test_read_trims_trailing_newlines() {
+        let inputs = Inputs::new(default_root());
+        let text = inputs.read(2022, 5).unwrap();
+        assert!(text.starts_with("[H]"));
+        assert!(!text.ends_with('\n'));
+    }
+}
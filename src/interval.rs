@@ -0,0 +1,297 @@
+use crate::error::{Error, Result};
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+/// Numbers an interval can be made of, any of the integer types.
+pub trait Endpoint:
+    Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> + From<u8>
+{
+    /// The number after this one, `None` past the largest of the type.
+    fn successor(self) -> Option<Self>;
+}
+
+macro_rules! endpoint {
+    ($($t:ty),*) => {
+        $(impl Endpoint for $t {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+        })*
+    };
+}
+
+endpoint!(u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+
+/// A half-open range of integers, `start` is in it and `end` is not.
+/// Puzzles usually give inclusive ranges, build those with
+/// [`Interval::inclusive`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// The parts of an interval before, inside and after another one, see
+/// [`Interval::split`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Split<T> {
+    pub before: Option<Interval<T>>,
+    pub inside: Option<Interval<T>>,
+    pub after: Option<Interval<T>>,
+}
+
+impl<T: Endpoint> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval from `first` to `last`, both included.  An error if
+    /// `last` is the largest number of the type, as the end would not fit.
+    pub fn inclusive(first: T, last: T) -> Result<Self> {
+        let end = last
+            .successor()
+            .ok_or_else(|| Error::new(format!("{:?} is too large to end a range", last)))?;
+        Ok(Self::new(first, end))
+    }
+
+    /// The last number in the interval.  Meaningless for an empty one.
+    pub fn last(&self) -> T {
+        self.end - T::from(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// How many numbers the interval holds.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::from(0)
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// Whether every number of `other` is in this interval.  An empty
+    /// interval is in every interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the two intervals share at least one number.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The numbers in both intervals, `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let x = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!x.is_empty()).then_some(x)
+    }
+
+    /// The two intervals as one, if they overlap or touch.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(*other);
+        }
+        if other.is_empty() {
+            return Some(*self);
+        }
+        (self.start <= other.end && other.start <= self.end)
+            .then(|| Self::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+
+    /// The numbers in this interval but not in `other`, at most one piece
+    /// on either side of it.
+    pub fn difference(&self, other: &Self) -> IntervalSet<T> {
+        let split = self.split(other);
+        split.before.into_iter().chain(split.after).collect()
+    }
+
+    /// Cuts this interval where `other` starts and ends.
+    pub fn split(&self, other: &Self) -> Split<T> {
+        let piece = |start: T, end: T| {
+            let x = Self::new(start.max(self.start), end.min(self.end));
+            (!x.is_empty()).then_some(x)
+        };
+        Split {
+            before: piece(self.start, other.start),
+            inside: self.intersection(other),
+            after: piece(other.end, self.end),
+        }
+    }
+}
+
+/// A set of numbers kept as sorted, disjoint intervals.  Overlapping and
+/// touching intervals are merged as they are added.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        self.intervals.push(interval);
+        self.normalize();
+    }
+
+    // Sorts the intervals and merges the ones that overlap or touch.
+    fn normalize(&mut self) {
+        self.intervals.retain(|x| !x.is_empty());
+        self.intervals.sort_by_key(|x| x.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match merged
+                .last_mut()
+                .and_then(|last| last.union(&interval).map(|u| (last, u)))
+            {
+                Some((last, union)) => *last = union,
+                None => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many numbers the set holds.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::from(0), |acc, x| acc + x.len())
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.intervals.iter().any(|i| i.contains(x))
+    }
+
+    /// Whether every number of `interval` is in the set.
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        interval.is_empty() || self.intervals.iter().any(|i| i.contains_interval(interval))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.iter()
+            .flat_map(|a| other.iter().filter_map(move |b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = Vec::with_capacity(self.intervals.len());
+        for a in self.iter() {
+            let mut rest = *a;
+            for b in other.iter().filter(|b| b.overlaps(a)) {
+                let split = rest.split(b);
+                out.extend(split.before);
+                match split.after {
+                    Some(x) => rest = x,
+                    None => {
+                        rest = Interval::new(rest.end, rest.end);
+                        break;
+                    }
+                }
+            }
+            out.push(rest);
+        }
+        out.into_iter().collect()
+    }
+}
+
+impl<T: Endpoint> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            intervals: iter.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn i(start: i64, end: i64) -> Interval<i64> {
+        Interval::new(start, end)
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals.iter().map(|&(a, b)| i(a, b)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::inclusive(2, 8).unwrap();
+        assert_eq!(a, i(2, 9));
+        assert_eq!((a.len(), a.last()), (7, 8));
+        assert!(a.contains_interval(&Interval::inclusive(3, 7).unwrap()));
+        assert!(!a.contains_interval(&i(3, 10)));
+        assert!(a.overlaps(&i(8, 10)) && !a.overlaps(&i(9, 10)));
+        assert_eq!(a.intersection(&i(5, 20)), Some(i(5, 9)));
+        assert_eq!(a.union(&i(9, 12)), Some(i(2, 12)));
+        assert_eq!(a.union(&i(10, 12)), None);
+        assert_eq!(i(5, 5).len(), 0);
+        assert!(Interval::inclusive(0, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_split() {
+        let split = i(0, 10).split(&i(3, 5));
+        assert_eq!(split.before, Some(i(0, 3)));
+        assert_eq!(split.inside, Some(i(3, 5)));
+        assert_eq!(split.after, Some(i(5, 10)));
+
+        let split = i(0, 10).split(&i(8, 20));
+        assert_eq!((split.inside, split.after), (Some(i(8, 10)), None));
+
+        let split = i(0, 10).split(&i(20, 30));
+        assert_eq!((split.before, split.inside), (Some(i(0, 10)), None));
+        assert_eq!(i(0, 10).difference(&i(3, 5)), set(&[(0, 3), (5, 10)]));
+    }
+
+    #[test]
+    fn test_set() {
+        let a = set(&[(5, 8), (0, 2), (1, 3), (3, 4)]);
+        assert_eq!(a.intervals(), [i(0, 4), i(5, 8)]);
+        assert_eq!(a.len(), 7);
+        assert!(a.contains(6) && !a.contains(4));
+        assert!(a.contains_interval(&i(1, 4)) && !a.contains_interval(&i(3, 6)));
+
+        let b = set(&[(2, 6), (7, 20)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(2, 4), (5, 6), (7, 8)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (6, 7)]));
+        assert_eq!(b.difference(&a), set(&[(4, 5), (8, 20)]));
+        assert!(a.difference(&set(&[(-5, 50)])).is_empty());
+    }
+}
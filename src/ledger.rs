@@ -0,0 +1,239 @@
+use crate::registry::DayPart;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Accepted answers for solved puzzles, stored one per line as
+/// `<year> <day> <part> <answer>`.  Newlines and backslashes inside an answer
+/// are escaped so multi-line answers still fit on one line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    answers: BTreeMap<DayPart, String>,
+}
+
+/// The ledger checked in next to the inputs.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.txt")
+}
+
+impl Ledger {
+    /// Loads a ledger from disk, a missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, LedgerError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(LedgerError::Io(e)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, LedgerError> {
+        let mut ledger = Self::default();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || LedgerError::Malformed {
+                line: idx + 1,
+                text: line.to_owned(),
+            };
+            let mut fields = line.splitn(4, ' ');
+            let mut number = || -> Result<u32, LedgerError> {
+                fields
+                    .next()
+                    .and_then(|x| x.parse().ok())
+                    .ok_or_else(malformed)
+            };
+            let dp = DayPart {
+                year: number()?,
+                day: number()?,
+                part: number()?,
+            };
+            let answer = fields.next().ok_or_else(malformed)?;
+            ledger.answers.insert(dp, unescape(answer));
+        }
+        Ok(ledger)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, dp: &DayPart) -> Option<&str> {
+        self.answers.get(dp).map(String::as_str)
+    }
+
+    pub fn record(&mut self, dp: DayPart, answer: &str) {
+        self.answers.insert(dp, answer.to_owned());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&DayPart, &str)> {
+        self.answers.iter().map(|(dp, x)| (dp, x.as_str()))
+    }
+
+    /// Compares freshly produced answers against the ledger.  Only ledger
+    /// entries that `in_scope` accepts can be reported as missing, so a
+    /// filtered run does not complain about the days it skipped.
+    pub fn check(
+        &self,
+        produced: &BTreeMap<DayPart, String>,
+        in_scope: impl Fn(&DayPart) -> bool,
+    ) -> Report {
+        let mut report = Report::default();
+        for (dp, actual) in produced {
+            match self.get(dp) {
+                Some(expected) if expected == actual => report.matched.push(*dp),
+                Some(expected) => report.mismatched.push(Mismatch {
+                    dp: *dp,
+                    expected: expected.to_owned(),
+                    actual: actual.clone(),
+                }),
+                None => report.new.push((*dp, actual.clone())),
+            }
+        }
+
+        report.missing = self
+            .answers
+            .keys()
+            .filter(|dp| in_scope(dp) && !produced.contains_key(dp))
+            .copied()
+            .collect();
+
+        report
+    }
+}
+
+impl Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# year day part answer")?;
+        for (dp, answer) in self.answers.iter() {
+            writeln!(f, "{} {} {} {}", dp.year, dp.day, dp.part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub dp: DayPart,
+    pub expected: String,
+    pub actual: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub matched: Vec<DayPart>,
+    pub mismatched: Vec<Mismatch>,
+
+    // Recorded in the ledger, but nothing produced an answer this time.
+    pub missing: Vec<DayPart>,
+
+    // Produced an answer that has not been recorded yet.
+    pub new: Vec<(DayPart, String)>,
+}
+
+impl Report {
+    pub fn is_regression(&self) -> bool {
+        !self.mismatched.is_empty() || !self.missing.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+    Io(io::Error),
+    Malformed { line: usize, text: String },
+}
+
+impl Display for LedgerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read answer ledger: {}", e),
+            Self::Malformed { line, text } => {
+                write!(f, "malformed answer ledger line {}: {:?}", line, text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dp(year: u32, day: u32, part: u32) -> DayPart {
+        DayPart { year, day, part }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut ledger = Ledger::default();
+        ledger.record(dp(2022, 1, 1), "67622");
+        ledger.record(dp(2022, 5, 1), "TQRFCBSJJ");
+        ledger.record(dp(2022, 10, 2), "#..#\n.#\\.");
+        ledger.record(dp(2018, 2, 2), "two words");
+
+        let text = ledger.to_string();
+        assert!(text.contains("2022 10 2 #..#\\n.#\\\\.\n"));
+        assert_eq!(Ledger::parse(&text).unwrap(), ledger);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let err = Ledger::parse("# comment\n2022 1 1 5\n2022 x 1 5\n").unwrap_err();
+        assert!(matches!(err, LedgerError::Malformed { line: 3, .. }));
+        assert!(Ledger::parse("2022 1 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let ledger = Ledger::parse("2022 1 1 10\n2022 1 2 20\n2022 2 1 30\n2021 1 1 40\n").unwrap();
+        let produced = BTreeMap::from([
+            (dp(2022, 1, 1), "10".to_string()),
+            (dp(2022, 1, 2), "21".to_string()),
+            (dp(2022, 3, 1), "50".to_string()),
+        ]);
+
+        let report = ledger.check(&produced, |dp| dp.year == 2022);
+        assert_eq!(report.matched, vec![dp(2022, 1, 1)]);
+        assert_eq!(
+            report.mismatched,
+            vec![Mismatch {
+                dp: dp(2022, 1, 2),
+                expected: "20".to_string(),
+                actual: "21".to_string(),
+            }]
+        );
+        assert_eq!(report.missing, vec![dp(2022, 2, 1)]);
+        assert_eq!(report.new, vec![(dp(2022, 3, 1), "50".to_string())]);
+        assert!(report.is_regression());
+
+        let report = ledger.check(&BTreeMap::new(), |_| false);
+        assert!(!report.is_regression());
+    }
+}
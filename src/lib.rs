@@ -1,29 +1,33 @@
-mod day25;
-mod day24;
-mod day23;
-mod day22;
-mod day21;
-mod day20;
-mod day19;
-mod day18;
-mod day17;
-mod day16;
-mod day15;
-mod day14;
-mod day13;
-mod day12;
-mod day11;
-use aoc_runner_derive::aoc_lib;
+use registry::Registry;
 
-pub mod day1;
-pub mod day10;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod bench;
+pub mod bitmap;
+pub mod cycle;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod ledger;
+pub mod parity;
+pub mod parse;
+pub mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod site;
+pub mod solution;
+pub mod submit;
+pub mod table;
+pub mod watch;
+pub mod year2018;
+pub mod year2021;
+pub mod year2022;
 
-aoc_lib! { year = 2022 }
+/// Builds a registry holding every solution from every year.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    year2018::register(&mut registry);
+    year2021::register(&mut registry);
+    year2022::register(&mut registry);
+    registry
+}
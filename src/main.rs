@@ -1,4 +1,803 @@
-use advent_of_code;
-use aoc_runner_derive::aoc_main;
+use advent_of_code::bench::{self, Measurement};
+use advent_of_code::input::Inputs;
+use advent_of_code::ledger::{self, Ledger};
+use advent_of_code::parity::{self, Comparison, ParityError};
+use advent_of_code::registry::{DayPart, Registry};
+use advent_of_code::scaffold;
+use advent_of_code::site::{self, Client, Config};
+use advent_of_code::submit::{self, History, Verdict};
+use advent_of_code::table::Table;
+use advent_of_code::watch::{self, Watcher};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
 
-aoc_main! { lib = advent_of_code }
+/// Runs the registered Advent of Code solutions.  With no filters every
+/// solution from every year is run.
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Read inputs from <DIR>/<year>/day<N>.txt instead of the repository's
+    /// input folder.  Can also be set with AOC_INPUT.
+    #[arg(long, value_name = "DIR", global = true)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions and print their answers, the default without a command
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Run every selected day and print a summary table per year,
+        /// carrying on past failures
+        #[arg(long)]
+        all: bool,
+
+        /// Also write the answers to <FILE>, in the answer ledger format
+        #[arg(long, value_name = "FILE", conflicts_with = "all")]
+        save: Option<PathBuf>,
+    },
+
+    /// Rerun solutions and compare their answers against the answer ledger
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Record answers that are not in the ledger yet
+        #[arg(long)]
+        accept: bool,
+
+        /// Answer ledger to check against, defaults to answers.txt
+        #[arg(long, value_name = "FILE")]
+        answers: Option<PathBuf>,
+    },
+
+    /// Time the generator and the solver of each selected solution separately
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Number of times each stage is run
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+
+    /// Download a day's input and example blocks into the input folder,
+    /// skipping files that are already there
+    Fetch {
+        #[arg(long)]
+        year: u32,
+
+        #[arg(long)]
+        day: u32,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+
+    /// Submit an answer, refusing ones the submission history already
+    /// rules out
+    Submit {
+        #[arg(long)]
+        year: u32,
+
+        #[arg(long)]
+        day: u32,
+
+        #[arg(long)]
+        part: u32,
+
+        /// The answer to submit, defaults to running the solution
+        answer: Option<String>,
+
+        /// Submission history to check against, defaults to submissions.txt
+        #[arg(long, value_name = "FILE")]
+        history: Option<PathBuf>,
+
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+
+    /// Rerun a day whenever its source or input changes, running its
+    /// examples first and showing how the answers changed
+    Watch {
+        /// Defaults to the latest year with this day
+        #[arg(long)]
+        year: Option<u32>,
+
+        #[arg(long)]
+        day: u32,
+    },
+
+    /// Run the rust and the python solutions on the same inputs and report
+    /// the parts where they disagree
+    Parity {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Python interpreter to run the python package with
+        #[arg(long, default_value = "python3")]
+        python: String,
+    },
+
+    /// Start a new day from a template, registering it and leaving an empty
+    /// input to paste into
+    New {
+        #[arg(long)]
+        year: u32,
+
+        #[arg(long)]
+        day: u32,
+    },
+}
+
+#[derive(Args)]
+struct SiteArgs {
+    /// Config file holding the session cookie, defaults to aoc.conf
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// Talk to this server instead of the one in the config
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+impl SiteArgs {
+    fn load(self) -> Result<Config, String> {
+        let path = self.config.unwrap_or_else(site::default_config_path);
+        let mut config = Config::load(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if let Some(url) = self.base_url {
+            config.base_url = url.trim_end_matches('/').to_owned();
+        }
+        Ok(config)
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Args, Clone, Copy)]
+struct Selection {
+    /// Only use solutions from this year
+    #[arg(long)]
+    year: Option<u32>,
+
+    /// Only use solutions for this day
+    #[arg(long)]
+    day: Option<u32>,
+
+    /// Only use this part
+    #[arg(long)]
+    part: Option<u32>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let registry = advent_of_code::registry();
+    let inputs = Inputs::resolve(cli.input);
+
+    match cli.command {
+        None => run(&registry, &inputs, cli.selection, None),
+        Some(Command::Run {
+            selection,
+            all: false,
+            save,
+        }) => run(&registry, &inputs, selection, save),
+        Some(Command::Run {
+            selection,
+            all: true,
+            ..
+        }) => run_all(&registry, &inputs, selection),
+        Some(Command::Verify {
+            selection,
+            accept,
+            answers,
+        }) => verify(&registry, &inputs, selection, accept, answers),
+        Some(Command::Bench {
+            selection,
+            iterations,
+            format,
+        }) => bench(&registry, &inputs, selection, iterations, format),
+        Some(Command::Fetch { year, day, site }) => fetch(&inputs, year, day, site),
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            history,
+            site,
+        }) => {
+            let dp = DayPart { year, day, part };
+            submit(&registry, &inputs, dp, answer, history, site)
+        }
+        Some(Command::Watch { year, day }) => watch(&registry, &inputs, year, day),
+        Some(Command::Parity { selection, python }) => {
+            parity(&registry, &inputs, selection, &python)
+        }
+        Some(Command::New { year, day }) => new(&inputs, year, day),
+    }
+}
+
+fn run(
+    registry: &Registry,
+    inputs: &Inputs,
+    selection: Selection,
+    save: Option<PathBuf>,
+) -> ExitCode {
+    let mut current_year = None;
+    let mut failed = false;
+    let mut answers = Ledger::default();
+    for (dp, solution) in registry.select(selection.year, selection.day, selection.part) {
+        if current_year != Some(dp.year) {
+            println!("Advent of code {}", dp.year);
+            current_year = Some(dp.year);
+        }
+
+        let input = match inputs.read(dp.year, dp.day) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("Day {} - Part {}: FAILED, {}\n", dp.day, dp.part, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut dispositions: String = registry
+            .test_examples(dp)
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        if !dispositions.is_empty() {
+            dispositions.insert(0, ' ');
+        }
+
+        let outcome = match solution.run(&input) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!(
+                    "Day {} - Part {}{}: FAILED\n{}\n",
+                    dp.day, dp.part, dispositions, e
+                );
+                failed = true;
+                continue;
+            }
+        };
+        answers.record(*dp, &outcome.answer);
+
+        // Drawn answers show their picture under the letters read from it,
+        // unless the picture could not be read and is the answer already.
+        let picture = match outcome.picture {
+            Some(x) if x != outcome.answer => format!("\n{}", x),
+            _ => String::new(),
+        };
+        println!(
+            "Day {} - Part {}{}: {}{}\n\tgenerator: {:?},\n\trunner: {:?}\n",
+            dp.day,
+            dp.part,
+            dispositions,
+            outcome.answer,
+            picture,
+            outcome.generator,
+            outcome.solver
+        );
+    }
+
+    if current_year.is_none() {
+        eprintln!("No solutions registered matching the given filters");
+        return ExitCode::FAILURE;
+    }
+    if let Some(path) = save {
+        if let Err(e) = answers.save(&path) {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_all(registry: &Registry, inputs: &Inputs, selection: Selection) -> ExitCode {
+    let mut failures = Vec::new();
+    let mut any = false;
+    for year in registry.years() {
+        if selection.year.is_some_and(|y| y != year) {
+            continue;
+        }
+
+        let mut table = Table::new(&[
+            "Day",
+            "Part",
+            "Examples",
+            "Answer",
+            "Generator",
+            "Runner",
+            "Total",
+        ])
+        .align_right(0)
+        .align_right(1)
+        .align_right(4)
+        .align_right(5)
+        .align_right(6);
+        let mut total = (Duration::ZERO, Duration::ZERO);
+        for (dp, solution) in registry.select(Some(year), selection.day, selection.part) {
+            any = true;
+            let mut cells = vec![dp.day.to_string(), dp.part.to_string()];
+            let dispositions = registry.test_examples(dp);
+            if dispositions.iter().any(|x| x.is_failure()) {
+                failures.push(format!("{}: failed its examples", dp));
+            }
+            cells.push(dispositions.iter().map(|x| x.to_string()).collect());
+
+            let outcome = inputs
+                .read(dp.year, dp.day)
+                .map_err(|e| e.to_string())
+                .and_then(|input| solution.run(&input).map_err(|e| e.to_string()));
+            match outcome {
+                Ok(outcome) => {
+                    // Multi-line answers would break the table, only their
+                    // first line is shown.
+                    let mut lines = outcome.answer.lines();
+                    let mut answer = lines.next().unwrap_or_default().to_owned();
+                    if lines.next().is_some() {
+                        answer.push_str(" …");
+                    }
+                    cells.push(answer);
+                    cells.push(format!("{:.2?}", outcome.generator));
+                    cells.push(format!("{:.2?}", outcome.solver));
+                    cells.push(format!("{:.2?}", outcome.generator + outcome.solver));
+                    total.0 += outcome.generator;
+                    total.1 += outcome.solver;
+                }
+                Err(e) => {
+                    cells.push("FAILED".to_owned());
+                    failures.push(format!("{}: FAILED\n{}", dp, e));
+                }
+            }
+            table.row(cells);
+        }
+
+        table.rule().row(vec![
+            "Total".to_owned(),
+            String::new(),
+            String::new(),
+            String::new(),
+            format!("{:.2?}", total.0),
+            format!("{:.2?}", total.1),
+            format!("{:.2?}", total.0 + total.1),
+        ]);
+        println!("Advent of code {}\n{}", year, table);
+    }
+
+    if !any {
+        eprintln!("No solutions registered matching the given filters");
+        return ExitCode::FAILURE;
+    }
+    for failure in failures.iter() {
+        eprintln!("{}\n", failure);
+    }
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(
+    registry: &Registry,
+    inputs: &Inputs,
+    selection: Selection,
+    accept: bool,
+    answers: Option<PathBuf>,
+) -> ExitCode {
+    let path = answers.unwrap_or_else(ledger::default_path);
+    let mut ledger = match Ledger::load(&path) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Solutions that error out count as failures even without a ledger
+    // entry to compare against.
+    let mut produced = BTreeMap::new();
+    let mut failed = 0;
+    for (dp, solution) in registry.select(selection.year, selection.day, selection.part) {
+        match inputs.read(dp.year, dp.day) {
+            Ok(input) => match solution.run(&input) {
+                Ok(outcome) => {
+                    produced.insert(*dp, outcome.answer);
+                }
+                Err(e) => {
+                    eprintln!("{}: FAILED\n{}\n", dp, e);
+                    failed += 1;
+                }
+            },
+            Err(e) => eprintln!("{}: {}", dp, e),
+        }
+    }
+
+    let report = ledger.check(&produced, |dp| {
+        dp.matches(selection.year, selection.day, selection.part)
+    });
+    for mismatch in report.mismatched.iter() {
+        println!(
+            "{}: MISMATCH, expected {:?} but got {:?}",
+            mismatch.dp, mismatch.expected, mismatch.actual
+        );
+    }
+    for dp in report.missing.iter() {
+        println!("{}: MISSING, expected {:?}", dp, ledger.get(dp).unwrap());
+    }
+    for (dp, answer) in report.new.iter() {
+        println!("{}: NEW {:?}", dp, answer);
+    }
+    println!(
+        "{} matched, {} mismatched, {} missing, {} new, {} failed",
+        report.matched.len(),
+        report.mismatched.len(),
+        report.missing.len(),
+        report.new.len(),
+        failed
+    );
+
+    if accept && !report.new.is_empty() {
+        for (dp, answer) in report.new.iter() {
+            ledger.record(*dp, answer);
+        }
+        if let Err(e) = ledger.save(&path) {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        println!(
+            "Recorded {} new answers in {}",
+            report.new.len(),
+            path.display()
+        );
+    }
+
+    if report.is_regression() || failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(
+    registry: &Registry,
+    inputs: &Inputs,
+    selection: Selection,
+    iterations: usize,
+    format: Format,
+) -> ExitCode {
+    let mut measurements: Vec<Measurement> = Vec::new();
+    let mut failed = false;
+    let mut any = false;
+    for (dp, solution) in registry.select(selection.year, selection.day, selection.part) {
+        any = true;
+        let result = inputs
+            .read(dp.year, dp.day)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                bench::measure(*dp, solution, &input, iterations).map_err(|e| e.to_string())
+            });
+        match result {
+            Ok(x) => measurements.extend(x),
+            Err(e) => {
+                eprintln!("{}: FAILED\n{}\n", dp, e);
+                failed = true;
+            }
+        }
+    }
+
+    if !any {
+        eprintln!("No solutions registered matching the given filters");
+        return ExitCode::FAILURE;
+    }
+
+    match format {
+        Format::Text => {
+            for m in measurements.iter() {
+                println!(
+                    "{} {:>9}: min {:>12?}  median {:>12?}  p95 {:>12?}",
+                    m.dp, m.stage, m.stats.min, m.stats.median, m.stats.p95
+                );
+            }
+        }
+        Format::Json => print!("{}", bench::to_json(&measurements)),
+        Format::Csv => print!("{}", bench::to_csv(&measurements)),
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(inputs: &Inputs, year: u32, day: u32, site: SiteArgs) -> ExitCode {
+    let config = match site.load() {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match Client::new(config).fetch(inputs, year, day) {
+        Ok(fetched) => {
+            for x in fetched.iter() {
+                let status = if x.cached { "cached" } else { "downloaded" };
+                println!("{:>10} {}", status, x.path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{} day {}: {}", year, day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(
+    registry: &Registry,
+    inputs: &Inputs,
+    dp: DayPart,
+    answer: Option<String>,
+    history: Option<PathBuf>,
+    site: SiteArgs,
+) -> ExitCode {
+    let config = match site.load() {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answer = match answer {
+        Some(x) => x,
+        None => {
+            let solution = match registry
+                .select(Some(dp.year), Some(dp.day), Some(dp.part))
+                .next()
+            {
+                Some((_, x)) => x,
+                None => {
+                    eprintln!("{}: no solution registered", dp);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let outcome = inputs
+                .read(dp.year, dp.day)
+                .map_err(|e| e.to_string())
+                .and_then(|input| solution.run(&input).map_err(|e| e.to_string()));
+            match outcome {
+                Ok(x) => x.answer,
+                Err(e) => {
+                    eprintln!("{}: FAILED\n{}", dp, e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let path = history.unwrap_or_else(submit::default_path);
+    let mut history = match History::load(&path) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let client = Client::new(config);
+    let verdict = submit::submit(&client, &mut history, dp, &answer, SystemTime::now());
+    if let Err(e) = history.save(&path) {
+        eprintln!("{}: {}", path.display(), e);
+        return ExitCode::FAILURE;
+    }
+    match verdict {
+        Ok(Verdict::Right) => {
+            println!("{}: {} is the right answer", dp, answer);
+            record_answer(dp, &answer)
+        }
+        Ok(verdict) => {
+            println!("{}: {} was {}", dp, answer, verdict);
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}: {}", dp, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Right answers go in the answer ledger too, so verify checks them from
+// then on.
+fn record_answer(dp: DayPart, answer: &str) -> ExitCode {
+    let path = ledger::default_path();
+    let mut ledger = match Ledger::load(&path) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if ledger.get(&dp).is_none() {
+        ledger.record(dp, answer);
+        if let Err(e) = ledger.save(&path) {
+            eprintln!("{}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        println!("Recorded it in {}", path.display());
+    }
+    ExitCode::SUCCESS
+}
+
+fn new(inputs: &Inputs, year: u32, day: u32) -> ExitCode {
+    match scaffold::scaffold(&scaffold::default_src(), inputs, year, day) {
+        Ok(changes) => {
+            for x in changes.iter() {
+                let status = if x.created { "created" } else { "edited" };
+                println!("{:>7} {}", status, x.path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{} day {}: {}", year, day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(registry: &Registry, inputs: &Inputs, year: Option<u32>, day: u32) -> ExitCode {
+    let year = match year.or_else(|| {
+        registry
+            .select(None, Some(day), None)
+            .map(|(dp, _)| dp.year)
+            .max()
+    }) {
+        Some(x) => x,
+        None => {
+            eprintln!("No solutions registered for day {}", day);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source = scaffold::default_src()
+        .join(format!("year{}", year))
+        .join(format!("day{}.rs", day));
+    let mut watcher = Watcher::new([source, inputs.path(year, day)]);
+    for path in watcher.paths() {
+        println!("Watching {}", path.display());
+    }
+
+    // Each run saves its answers here for the loop to read back, under
+    // `target/` so an interrupted session leaves nothing anywhere else.
+    let saved = root
+        .join("target")
+        .join(format!("watch-{}-day{}.txt", year, day));
+    let cargo = || {
+        let mut command = std::process::Command::new("cargo");
+        command.current_dir(root);
+        command
+    };
+    let mut previous: Option<Ledger> = None;
+    loop {
+        println!("\n== {} day {} ==", year, day);
+        let built = cargo().args(["build", "--quiet"]).status();
+        if !built.is_ok_and(|x| x.success()) {
+            println!("Build failed, waiting for changes");
+        } else if !cargo()
+            .args(["test", "--quiet", "--lib"])
+            .arg(format!("year{}::day{}::example_tests", year, day))
+            .status()
+            .is_ok_and(|x| x.success())
+        {
+            println!("Examples failed, not running the input");
+        } else {
+            let _ = std::fs::remove_file(&saved);
+            let _ = cargo()
+                .args(["run", "--quiet", "--", "run"])
+                .args(["--year", &year.to_string(), "--day", &day.to_string()])
+                .arg("--input")
+                .arg(inputs.root())
+                .arg("--save")
+                .arg(&saved)
+                .status();
+            // Nothing is saved when every part failed, the failures have
+            // been printed already.
+            let answers = saved.exists().then(|| Ledger::load(&saved).ok()).flatten();
+            let _ = std::fs::remove_file(&saved);
+            if let Some(answers) = answers {
+                print!("{}", watch::compare(previous.as_ref(), &answers));
+                previous = Some(answers);
+            }
+        }
+
+        while !watcher.changed() {
+            std::thread::sleep(Duration::from_millis(500));
+        }
+    }
+}
+
+fn parity(registry: &Registry, inputs: &Inputs, selection: Selection, python: &str) -> ExitCode {
+    let mut days = BTreeMap::new();
+    for (dp, solution) in registry.select(selection.year, selection.day, selection.part) {
+        days.entry((dp.year, dp.day))
+            .or_insert_with(Vec::new)
+            .push((*dp, solution));
+    }
+
+    let root = parity::default_python_root();
+    let mut comparisons = Vec::new();
+    let mut failed = false;
+    for ((year, day), solutions) in days {
+        let path = inputs.path(year, day);
+        if !path.exists() {
+            continue;
+        }
+        let answers = match parity::python_answers(python, &root, year, day, &path) {
+            Ok(Some(x)) => x,
+            Ok(None) => continue,
+            Err(e @ ParityError::Spawn { .. }) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+            Err(e) => {
+                eprintln!("{}\n", e);
+                failed = true;
+                continue;
+            }
+        };
+
+        for (dp, solution) in solutions {
+            let rust = inputs
+                .read(dp.year, dp.day)
+                .map_err(|e| e.to_string())
+                .and_then(|input| solution.run(&input).map_err(|e| e.to_string()))
+                .map(|x| x.answer);
+            let comparison = Comparison {
+                dp,
+                rust,
+                python: answers.get(&dp.part).cloned(),
+            };
+            println!("{}", comparison);
+            comparisons.push(comparison);
+        }
+    }
+
+    if comparisons.is_empty() && !failed {
+        eprintln!("No days with both a rust and a python solution matching the given filters");
+        return ExitCode::FAILURE;
+    }
+    let agreed = comparisons.iter().filter(|x| x.agrees()).count();
+    println!("{} agree, {} disagree", agreed, comparisons.len() - agreed);
+    if failed || agreed < comparisons.len() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
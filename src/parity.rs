@@ -0,0 +1,186 @@
+use crate::ledger::unescape;
+use crate::registry::DayPart;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The python package next to the rust one.
+pub fn default_python_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("python")
+}
+
+// Runs one day of the python package on a given input file.  Answers are
+// printed one per line as `answer <part> <answer>` or `error <part>
+// <message>` with newlines escaped like the answer ledger does, everything
+// else on stdout is chatter from the package.  Exits with 3 if there is no
+// such day.
+const SCRIPT: &str = r#"
+import importlib, sys, traceback
+from pathlib import Path
+
+def escape(text):
+    return str(text).replace("\\", "\\\\").replace("\n", "\\n")
+
+year, day, path = sys.argv[1], int(sys.argv[2]), sys.argv[3]
+try:
+    package = importlib.import_module(f"aoc.year{year}")
+except ModuleNotFoundError:
+    sys.exit(3)
+found = getattr(package, f"year{year}").days.get(day)
+if found is None:
+    sys.exit(3)
+found._input = Path(path)
+for part in (1, 2):
+    if not getattr(found, f"has_part{part}"):
+        continue
+    try:
+        answer = getattr(found, f"run_part{part}")()
+        print(f"answer {part} {escape(answer)}")
+    except Exception:
+        print(f"error {part} {escape(traceback.format_exc().strip())}")
+"#;
+
+/// What one implementation made of a part: its answer or why it has none.
+pub type Verdict = Result<String, String>;
+
+/// The answers the python solution for a day gives for `input`, by part.
+/// `None` if the python package has no solution for the day.
+pub fn python_answers(
+    python: &str,
+    root: &Path,
+    year: u32,
+    day: u32,
+    input: &Path,
+) -> Result<Option<BTreeMap<u32, Verdict>>, ParityError> {
+    let output = Command::new(python)
+        .current_dir(root)
+        .arg("-c")
+        .arg(SCRIPT)
+        .arg(year.to_string())
+        .arg(day.to_string())
+        .arg(input)
+        .output()
+        .map_err(|source| ParityError::Spawn {
+            python: python.to_owned(),
+            source,
+        })?;
+    match output.status.code() {
+        Some(0) => Ok(Some(parse_output(&String::from_utf8_lossy(&output.stdout)))),
+        Some(3) => Ok(None),
+        _ => Err(ParityError::Failed {
+            year,
+            day,
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+        }),
+    }
+}
+
+fn parse_output(stdout: &str) -> BTreeMap<u32, Verdict> {
+    let mut answers = BTreeMap::new();
+    for line in stdout.lines() {
+        let mut fields = line.splitn(3, ' ');
+        let (Some(kind), Some(part), Some(value)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let Ok(part) = part.parse() else {
+            continue;
+        };
+        match kind {
+            "answer" => answers.insert(part, Ok(unescape(value))),
+            "error" => answers.insert(part, Err(unescape(value))),
+            _ => continue,
+        };
+    }
+    answers
+}
+
+/// How the two implementations of one part compare.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub dp: DayPart,
+    pub rust: Verdict,
+    pub python: Option<Verdict>,
+}
+
+impl Comparison {
+    /// Both came up with the same answer.
+    pub fn agrees(&self) -> bool {
+        matches!((&self.rust, &self.python), (Ok(a), Some(Ok(b))) if a == b)
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |x: &Verdict| match x {
+            Ok(answer) => format!("{:?}", answer),
+            Err(e) => format!("FAILED ({})", e.lines().last().unwrap_or_default()),
+        };
+        match &self.python {
+            _ if self.agrees() => write!(f, "{}: both {}", self.dp, show(&self.rust)),
+            Some(python) => write!(
+                f,
+                "{}: DISAGREE, rust {} but python {}",
+                self.dp,
+                show(&self.rust),
+                show(python)
+            ),
+            None => write!(f, "{}: python has no part {}", self.dp, self.dp.part),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParityError {
+    Spawn { python: String, source: io::Error },
+    Failed { year: u32, day: u32, stderr: String },
+}
+
+impl Display for ParityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spawn { python, source } => write!(f, "failed to run {}: {}", python, source),
+            Self::Failed { year, day, stderr } => {
+                write!(f, "python failed on {} day {}\n{}", year, day, stderr)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParityError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_output() {
+        let stdout = "Imported!\nanswer 1 67622\nerror 2 Traceback\\nValueError: x\nanswer 3\n";
+        let answers = parse_output(stdout);
+        assert_eq!(
+            answers,
+            BTreeMap::from([
+                (1, Ok("67622".to_owned())),
+                (2, Err("Traceback\nValueError: x".to_owned()))
+            ])
+        );
+
+        let dp = DayPart {
+            year: 2022,
+            day: 1,
+            part: 2,
+        };
+        let comparison = Comparison {
+            dp,
+            rust: Ok("45000".to_owned()),
+            python: answers.get(&2).cloned(),
+        };
+        assert!(!comparison.agrees());
+        assert_eq!(
+            comparison.to_string(),
+            "2022 Day 1 - Part 2: DISAGREE, rust \"45000\" but python FAILED (ValueError: x)"
+        );
+    }
+}
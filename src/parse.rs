@@ -0,0 +1,324 @@
+use crate::error::{Error, Result};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A piece of a puzzle input that remembers the whole input it was cut
+/// from, so anything wrong with it can be reported with a line and column.
+/// Every way of cutting it up hands out more `Text`s, generators only drop
+/// down to `&str` once they have what they want.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Text<'a> {
+    input: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Text<'a> {
+    /// The whole input.
+    pub fn new(input: &'a str) -> Self {
+        Self { input, text: input }
+    }
+
+    // `text` must be a slice of the input.
+    fn sub(&self, text: &'a str) -> Self {
+        Self {
+            input: self.input,
+            text,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error pointing at this text.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::at(self.input, self.text, message)
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    /// The lines of the text.  Line endings are dropped whether they are
+    /// `\n` or `\r\n`, and a trailing newline does not add an empty line.
+    pub fn lines(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let this = *self;
+        self.text.lines().map(move |x| this.sub(x))
+    }
+
+    /// The groups of lines separated by blank lines.  Runs of blank lines
+    /// and blank lines at either end are skipped.
+    pub fn blocks(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let mut blocks = Vec::new();
+        let mut current: Option<(usize, usize)> = None;
+        let mut offset = 0;
+        for line in self.text.split_inclusive('\n') {
+            let content = line.trim_end_matches('\n').trim_end_matches('\r');
+            if content.trim().is_empty() {
+                blocks.extend(current.take());
+            } else {
+                let end = offset + content.len();
+                current = Some((current.map_or(offset, |(start, _)| start), end));
+            }
+            offset += line.len();
+        }
+        blocks.extend(current);
+
+        let this = *self;
+        blocks
+            .into_iter()
+            .map(move |(start, end)| this.sub(&this.text[start..end]))
+    }
+
+    /// Splits the text everywhere `separator` appears.
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Text<'a>> + 'a {
+        let this = *self;
+        self.text.split(separator).map(move |x| this.sub(x))
+    }
+
+    /// Splits the text at the first `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(Text<'a>, Text<'a>)> {
+        let (l, r) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected {:?}", separator)))?;
+        Ok((self.sub(l), self.sub(r)))
+    }
+
+    /// The part of the text in the byte `range`, an error if the range is
+    /// out of bounds or splits a character.
+    pub fn slice(&self, range: Range<usize>) -> Result<Text<'a>> {
+        match self.text.get(range.clone()) {
+            Some(x) => Ok(self.sub(x)),
+            None => Err(self.error(format!(
+                "cannot take bytes {}..{} of this text",
+                range.start, range.end
+            ))),
+        }
+    }
+
+    /// Splits the text on runs of whitespace.
+    pub fn words(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let this = *self;
+        self.text.split_whitespace().map(move |x| this.sub(x))
+    }
+
+    /// The text after `prefix`, an error if it does not start with it.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Text<'a>> {
+        match self.text.strip_prefix(prefix) {
+            Some(x) => Ok(self.sub(x)),
+            None => Err(self.error(format!("expected {:?}", prefix))),
+        }
+    }
+
+    /// Parses the text, ignoring surrounding whitespace, as a number or
+    /// anything else with a [`FromStr`] impl.
+    pub fn number<T: FromStr>(&self) -> Result<T> {
+        let text = self.trim();
+        text.text.parse().map_err(|_| {
+            let what = std::any::type_name::<T>();
+            text.error(format!("expected a number that fits in {}", what))
+        })
+    }
+
+    /// Every integer in the text, whatever separates them.  A `-` directly
+    /// in front of digits is a sign unless it follows a letter or digit, so
+    /// `2-4` is two numbers and `x=-3` is one.
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>> {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let signed = bytes[idx] == b'-'
+                && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit)
+                && (idx == 0 || !bytes[idx - 1].is_ascii_alphanumeric());
+            if !signed && !bytes[idx].is_ascii_digit() {
+                idx += 1;
+                continue;
+            }
+
+            let start = idx;
+            idx += 1;
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+            numbers.push(self.sub(&self.text[start..idx]).number()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Reads a `key: value` pair.  Both halves are trimmed.
+    pub fn record(&self) -> Result<(Text<'a>, Text<'a>)> {
+        let (key, value) = self.split_once(":")?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Reads one `key: value` pair per line, skipping blank lines.
+    pub fn records(&self) -> Result<Vec<(Text<'a>, Text<'a>)>> {
+        self.lines()
+            .filter(|x| !x.trim().is_empty())
+            .map(|x| x.record())
+            .collect()
+    }
+
+    /// Cuts the text into columns `width` characters wide, for fixed layout
+    /// tables like a crate diagram.  The last column may be narrower.
+    pub fn columns(&self, width: usize) -> impl Iterator<Item = Text<'a>> + 'a {
+        assert!(width > 0, "columns must be at least 1 character wide");
+        let this = *self;
+        let text = self.text.trim_end_matches('\r');
+        let mut bounds: Vec<usize> = text
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .step_by(width)
+            .collect();
+        bounds.push(text.len());
+        let columns: Vec<Text<'a>> = bounds
+            .windows(2)
+            .map(|x| this.sub(&text[x[0]..x[1]]))
+            .collect();
+        columns.into_iter()
+    }
+
+    /// Matches the text against a pattern where each `{}` stands for a
+    /// value, returning the values.  `"move {} from {} to {}"` reads
+    /// `move 1 from 2 to 3` as `["1", "2", "3"]`.  A value runs until the
+    /// text after its `{}` shows up, so two `{}` cannot be next to each
+    /// other.
+    pub fn scan<const N: usize>(&self, pattern: &str) -> Result<[Text<'a>; N]> {
+        let literals: Vec<&str> = pattern.split("{}").collect();
+        assert_eq!(literals.len(), N + 1, "pattern must have {} values", N);
+
+        let mut rest = self.sub(self.text.trim_end_matches('\r'));
+        let mut values = [*self; N];
+        for (idx, literal) in literals.iter().enumerate() {
+            rest = rest.strip_prefix(literal).map_err(|_| {
+                let at = rest.text.get(..literal.len()).unwrap_or(rest.text);
+                self.sub(at)
+                    .error(format!("expected {:?} to match {:?}", literal, pattern))
+            })?;
+            if idx == N {
+                break;
+            }
+
+            let next = literals[idx + 1];
+            assert!(
+                !next.is_empty() || idx + 1 == N,
+                "values in {:?} need text between them",
+                pattern
+            );
+            let end = match next {
+                "" => rest.text.len(),
+                _ => rest.text.find(next).ok_or_else(|| {
+                    self.sub(&rest.text[rest.text.len()..])
+                        .error(format!("expected {:?} to match {:?}", next, pattern))
+                })?,
+            };
+            let value = rest.sub(&rest.text[..end]);
+            if value.is_empty() {
+                return Err(value.error(format!("expected a value to match {:?}", pattern)));
+            }
+            values[idx] = value;
+            rest = rest.sub(&rest.text[end..]);
+        }
+
+        if !rest.is_empty() {
+            return Err(rest.error(format!("unexpected text after {:?}", pattern)));
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = "1\r\n2\r\n\r\n3\n\n\n4\n5\n\n";
+        let text = Text::new(input);
+        let blocks: Vec<&str> = text.blocks().map(|x| x.as_str()).collect();
+        assert_eq!(blocks, ["1\r\n2", "3", "4\n5"]);
+
+        let numbers: Vec<Vec<u32>> = text
+            .blocks()
+            .map(|b| b.lines().map(|x| x.number()).collect::<Result<_>>())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, [vec![1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let text = Text::new("2-4,6-8 x=-3, y=+7 -12");
+        assert_eq!(text.numbers::<i32>().unwrap(), [2, 4, 6, 8, -3, 7, -12]);
+        assert!(text.numbers::<u32>().is_err());
+
+        let error = Text::new("1\n99999999999").numbers::<u32>().unwrap_err();
+        assert_eq!(error.position.unwrap().line, 2);
+        assert_eq!(error.message, "expected a number that fits in u32");
+    }
+
+    #[test]
+    fn test_records() {
+        let text = Text::new("Monkey: 0\r\n  Test: divisible by 23\n\n");
+        let records: Vec<(&str, &str)> = text
+            .records()
+            .unwrap()
+            .into_iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(records, [("Monkey", "0"), ("Test", "divisible by 23")]);
+
+        let error = Text::new("a: 1\nb 2").records().unwrap_err();
+        assert_eq!(error.position.unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_slice() {
+        let text = Text::new("[Z] [M] [P]");
+        let cell = text.slice(4..7).unwrap();
+        assert_eq!(cell.as_str(), "[M]");
+        assert_eq!(cell.error("x").position.unwrap().column, 5);
+        assert!(text.slice(8..12).is_err());
+
+        let error = Text::new("[É]").slice(0..2).unwrap_err();
+        assert_eq!(error.message, "cannot take bytes 0..2 of this text");
+    }
+
+    #[test]
+    fn test_columns() {
+        let text = Text::new("[Z] [É] [P]\r");
+        let cells: Vec<Text> = text.columns(4).collect();
+        let names: Vec<&str> = cells.iter().map(|x| x.trim().as_str()).collect();
+        assert_eq!(names, ["[Z]", "[É]", "[P]"]);
+        assert_eq!(cells[2].error("x").position.unwrap().column, 9);
+        assert_eq!(Text::new("").columns(4).count(), 0);
+    }
+
+    #[test]
+    fn test_scan() {
+        let input = "move 1 from 2 to 3\r\nmove 10 from 2\n$ ls -la";
+        let mut lines = Text::new(input).lines();
+
+        let [count, from, to] = lines.next().unwrap().scan("move {} from {} to {}").unwrap();
+        assert_eq!(count.number::<u32>().unwrap(), 1);
+        assert_eq!((from.as_str(), to.as_str()), ("2", "3"));
+
+        let error = lines
+            .next()
+            .unwrap()
+            .scan::<3>("move {} from {} to {}")
+            .unwrap_err();
+        let position = error.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 15));
+
+        let error = lines.next().unwrap().scan::<0>("$ ls").unwrap_err();
+        assert_eq!(error.message, "unexpected text after \"$ ls\"");
+        assert!(Text::new("$ cd /").scan::<0>("$ cd /").is_ok());
+    }
+}
@@ -0,0 +1,607 @@
+use crate::bitmap::Bitmap;
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::time::{Duration, Instant};
+
+/// Identifies a single solution.  Ordering is by year, then day, then part so
+/// iterating the registry walks the history in puzzle order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayPart {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+}
+
+impl DayPart {
+    /// Whether this matches every filter that is set.
+    pub fn matches(&self, year: Option<u32>, day: Option<u32>, part: Option<u32>) -> bool {
+        year.is_none_or(|y| y == self.year)
+            && day.is_none_or(|d| d == self.day)
+            && part.is_none_or(|p| p == self.part)
+    }
+}
+
+impl Display for DayPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Day {} - Part {}", self.year, self.day, self.part)
+    }
+}
+
+type Generator = Box<dyn Fn(&str) -> Result<Box<dyn Any>>>;
+type Solver = Box<dyn Fn(&dyn Any) -> Result<Answer>>;
+
+/// Marks generators and solvers that return their value directly.
+pub struct Infallible;
+
+/// Marks generators and solvers that return a [`Result`].
+pub struct Fallible;
+
+/// What a generator can return: the parsed input, or a `Result` of it when
+/// the input might be malformed.  The marker only exists to keep the two
+/// impls apart.
+pub trait Generated<B: ?Sized, M> {
+    type Output: Borrow<B> + 'static;
+
+    fn into_result(self) -> Result<Self::Output>;
+}
+
+impl<G: Borrow<B> + 'static, B: ?Sized> Generated<B, Infallible> for G {
+    type Output = G;
+
+    fn into_result(self) -> Result<G> {
+        Ok(self)
+    }
+}
+
+impl<G: Borrow<B> + 'static, B: ?Sized> Generated<B, Fallible> for Result<G> {
+    type Output = G;
+
+    fn into_result(self) -> Result<G> {
+        self
+    }
+}
+
+/// Marks solvers that draw their answer as a [`Bitmap`].
+pub struct Drawn;
+
+/// What a solver can return: anything printable, a [`Bitmap`], or a
+/// `Result` of either.
+pub trait IntoAnswer<M> {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+impl<A: Display> IntoAnswer<Infallible> for A {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(Answer {
+            text: self.to_string(),
+            picture: None,
+        })
+    }
+}
+
+impl IntoAnswer<Drawn> for Bitmap {
+    fn into_answer(self) -> Result<Answer> {
+        let picture = self.render();
+        Ok(Answer {
+            text: self.ocr().unwrap_or_else(|| picture.clone()),
+            picture: Some(picture),
+        })
+    }
+}
+
+impl IntoAnswer<Infallible> for Answer {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(self)
+    }
+}
+
+impl<A: IntoAnswer<M>, M> IntoAnswer<(Fallible, M)> for Result<A> {
+    fn into_answer(self) -> Result<Answer> {
+        self?.into_answer()
+    }
+}
+
+/// What a solver came up with.  `text` is what gets submitted and compared
+/// against examples and the ledger, drawn answers also keep their picture.
+/// A picture that cannot be read is its own text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub text: String,
+    pub picture: Option<String>,
+}
+
+/// A generator paired with the solver that consumes its output, one part of
+/// a day.  The parsed input is type erased so solutions for every day can
+/// live in one map.
+pub struct Part {
+    generator: Generator,
+    solver: Solver,
+}
+
+impl Part {
+    pub fn generate(&self, input: &str) -> Result<Box<dyn Any>> {
+        (self.generator)(input)
+    }
+
+    pub fn solve(&self, parsed: &dyn Any) -> Result<Answer> {
+        (self.solver)(parsed)
+    }
+
+    /// Generates and solves in one go, timing both stages.
+    pub fn run(&self, input: &str) -> Result<Outcome> {
+        let start_time = Instant::now();
+        let parsed = self.generate(input)?;
+        let inter_time = Instant::now();
+        let answer = self.solve(&*parsed)?;
+        let final_time = Instant::now();
+        Ok(Outcome {
+            answer: answer.text,
+            picture: answer.picture,
+            generator: inter_time - start_time,
+            solver: final_time - inter_time,
+        })
+    }
+}
+
+/// The answer a solution produced and how long each stage took.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub answer: String,
+    pub picture: Option<String>,
+    pub generator: Duration,
+    pub solver: Duration,
+}
+
+impl Debug for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Part").finish_non_exhaustive()
+    }
+}
+
+/// An example input from the puzzle text along with the answers it is known
+/// to produce.  Parts without an expected answer are left untested.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn new(input: &'static str) -> Self {
+        Self {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn part1(mut self, expected: impl Display) -> Self {
+        self.part1 = Some(expected.to_string());
+        self
+    }
+
+    pub fn part2(mut self, expected: impl Display) -> Self {
+        self.part2 = Some(expected.to_string());
+        self
+    }
+
+    pub fn expected(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of running a solution against one example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Disposition {
+    Pass,
+    Fail { expected: String, actual: String },
+
+    // The example did not produce an answer at all.
+    Error(Error),
+    Untested,
+}
+
+impl Disposition {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail { .. } | Self::Error(_))
+    }
+}
+
+impl Display for Disposition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "🟢"),
+            Self::Fail { .. } | Self::Error(_) => write!(f, "🔴"),
+            Self::Untested => write!(f, "⚪"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    solutions: BTreeMap<DayPart, Part>,
+    examples: BTreeMap<(u32, u32), Vec<Example>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts registering the solutions for a single day.
+    pub fn day(&mut self, year: u32, day: u32) -> Day<'_> {
+        Day {
+            registry: self,
+            year,
+            day,
+        }
+    }
+
+    pub fn get(&self, dp: &DayPart) -> Option<&Part> {
+        self.solutions.get(dp)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&DayPart, &Part)> {
+        self.solutions.iter()
+    }
+
+    /// Iterates the solutions matching every filter that is set.
+    pub fn select(
+        &self,
+        year: Option<u32>,
+        day: Option<u32>,
+        part: Option<u32>,
+    ) -> impl Iterator<Item = (&DayPart, &Part)> {
+        self.iter()
+            .filter(move |(dp, _)| dp.matches(year, day, part))
+    }
+
+    pub fn examples(&self, year: u32, day: u32) -> &[Example] {
+        self.examples
+            .get(&(year, day))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Runs a solution against every example registered for its day, in
+    /// registration order.
+    pub fn test_examples(&self, dp: &DayPart) -> Vec<Disposition> {
+        let solution = match self.get(dp) {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+
+        self.examples(dp.year, dp.day)
+            .iter()
+            .map(|example| match example.expected(dp.part) {
+                Some(expected) => match solution
+                    .generate(example.input.trim_end_matches('\n'))
+                    .and_then(|parsed| solution.solve(&*parsed))
+                {
+                    Ok(actual) if actual.text == expected => Disposition::Pass,
+                    Ok(actual) => Disposition::Fail {
+                        expected: expected.to_owned(),
+                        actual: actual.text,
+                    },
+                    Err(e) => Disposition::Error(e),
+                },
+                None => Disposition::Untested,
+            })
+            .collect()
+    }
+
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.solutions.keys().map(|dp| dp.year).collect();
+        years.dedup();
+        years
+    }
+}
+
+pub struct Day<'a> {
+    registry: &'a mut Registry,
+    year: u32,
+    day: u32,
+}
+
+impl Day<'_> {
+    /// Registers part 1.  The solver receives the generator output by
+    /// reference, borrowed as whatever it asks for (`&Vec<T>` as `&[T]` and
+    /// so on).  Days without a generator can pass `str::to_owned`.  Either
+    /// function may return a [`Result`] instead, an error is reported
+    /// against this day rather than aborting the run.
+    pub fn part1<G, B, A, GM, AM>(
+        &mut self,
+        generator: fn(&str) -> G,
+        solver: fn(&B) -> A,
+    ) -> &mut Self
+    where
+        G: Generated<B, GM> + 'static,
+        B: ?Sized + 'static,
+        A: IntoAnswer<AM> + 'static,
+    {
+        self.part(1, generator, solver)
+    }
+
+    /// Registers part 2, see [`Day::part1`].
+    pub fn part2<G, B, A, GM, AM>(
+        &mut self,
+        generator: fn(&str) -> G,
+        solver: fn(&B) -> A,
+    ) -> &mut Self
+    where
+        G: Generated<B, GM> + 'static,
+        B: ?Sized + 'static,
+        A: IntoAnswer<AM> + 'static,
+    {
+        self.part(2, generator, solver)
+    }
+
+    fn part<G, B, A, GM, AM>(
+        &mut self,
+        part: u32,
+        generator: fn(&str) -> G,
+        solver: fn(&B) -> A,
+    ) -> &mut Self
+    where
+        G: Generated<B, GM> + 'static,
+        B: ?Sized + 'static,
+        A: IntoAnswer<AM> + 'static,
+    {
+        let (year, day) = (self.year, self.day);
+        let dp = DayPart { year, day, part };
+        let solution = Part {
+            generator: Box::new(move |input| {
+                let parsed = generator(input)
+                    .into_result()
+                    .map_err(|e| e.in_day(year, day))?;
+                Ok(Box::new(parsed) as Box<dyn Any>)
+            }),
+            solver: Box::new(move |parsed| {
+                let parsed = parsed
+                    .downcast_ref::<G::Output>()
+                    .expect("parsed input does not match the registered generator");
+                solver(parsed.borrow())
+                    .into_answer()
+                    .map_err(|e| e.in_day(year, day))
+            }),
+        };
+        let previous = self.registry.solutions.insert(dp, solution);
+        assert!(previous.is_none(), "{} registered twice", dp);
+        self
+    }
+
+    /// Registers a day written as a [`Solution`], its parts and its
+    /// examples.
+    pub fn solution<S: Solution>(&mut self) -> &mut Self {
+        self.part(1, S::parse, S::part1);
+        if let Some(part2) = S::PART2 {
+            self.part(2, S::parse, part2);
+        }
+        self.examples(S::examples())
+    }
+
+    /// Attaches the examples the parts of this day are checked against,
+    /// usually the list built by [`examples!`](crate::examples).
+    pub fn examples(&mut self, examples: Vec<Example>) -> &mut Self {
+        self.registry
+            .examples
+            .entry((self.year, self.day))
+            .or_default()
+            .extend(examples);
+        self
+    }
+}
+
+/// Runs the examples of one part of the day `register` registers, panicking
+/// on the first that does not produce its expected answer.  Tests call this
+/// through [`examples!`](crate::examples) or [`check`](crate::solution::check).
+pub fn check_examples(register: impl FnOnce(&mut Day), part: u32) {
+    let mut registry = Registry::new();
+    register(&mut registry.day(0, 0));
+    let dp = DayPart {
+        year: 0,
+        day: 0,
+        part,
+    };
+    for (idx, disposition) in registry.test_examples(&dp).into_iter().enumerate() {
+        match disposition {
+            Disposition::Fail { expected, actual } => panic!(
+                "example {} part {}: expected {:?}, got {:?}",
+                idx + 1,
+                part,
+                expected,
+                actual
+            ),
+            Disposition::Error(e) => panic!("example {} part {}: {}", idx + 1, part, e),
+            _ => {}
+        }
+    }
+}
+
+/// Declares the examples for a day, the same way the python `@day.test`
+/// decorator does.  Expands to an `examples()` function for
+/// [`Day::examples`] and `test_part1`/`test_part2` tests that run every
+/// example through the module's `register` function.
+///
+/// ```text
+/// examples! {
+///     INPUT_TEXT => part1: 24000, part2: 45000;
+///     MORE_INPUT_TEXT => part2: 36;
+/// }
+/// ```
+///
+/// A day nobody has read the examples for yet can leave it empty.
+#[macro_export]
+macro_rules! examples {
+    () => {
+        fn examples() -> Vec<$crate::registry::Example> {
+            Vec::new()
+        }
+    };
+    ($($input:expr => $($part:ident: $expected:expr),+);+ $(;)?) => {
+        fn examples() -> Vec<$crate::registry::Example> {
+            vec![$($crate::registry::Example::new($input)$(.$part($expected))+),+]
+        }
+
+        #[cfg(test)]
+        mod example_tests {
+            fn check(part: u32) {
+                $crate::registry::check_examples(super::register, part);
+            }
+
+            #[test]
+            fn test_part1() {
+                check(1);
+            }
+
+            #[test]
+            fn test_part2() {
+                check(2);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generator(input: &str) -> Vec<u32> {
+        input.lines().map(|x| x.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &[u32]) -> u32 {
+        input.iter().sum()
+    }
+
+    fn part2(input: &str) -> usize {
+        input.len()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut registry = Registry::new();
+        registry
+            .day(2022, 1)
+            .part1(generator, part1)
+            .part2(str::to_owned, part2);
+
+        let dp = DayPart {
+            year: 2022,
+            day: 1,
+            part: 1,
+        };
+        let solution = registry.get(&dp).unwrap();
+        let parsed = solution.generate("1\n2\n3").unwrap();
+        assert_eq!(solution.solve(&*parsed).unwrap().text, "6");
+
+        let dp = DayPart { part: 2, ..dp };
+        let solution = registry.get(&dp).unwrap();
+        let parsed = solution.generate("1\n2\n3").unwrap();
+        assert_eq!(solution.solve(&*parsed).unwrap().text, "5");
+    }
+
+    #[test]
+    fn test_examples() {
+        let mut registry = Registry::new();
+        registry
+            .day(2022, 1)
+            .part1(generator, part1)
+            .part2(str::to_owned, part2)
+            .examples(vec![
+                Example::new("1\n2\n").part1(3).part2(3),
+                Example::new("4\n5").part1(10),
+            ]);
+
+        let dp = DayPart {
+            year: 2022,
+            day: 1,
+            part: 1,
+        };
+        let dispositions = registry.test_examples(&dp);
+        assert_eq!(dispositions[0], Disposition::Pass);
+        assert_eq!(
+            dispositions[1],
+            Disposition::Fail {
+                expected: "10".to_string(),
+                actual: "9".to_string(),
+            }
+        );
+
+        let dp = DayPart { part: 2, ..dp };
+        let dispositions = registry.test_examples(&dp);
+        assert_eq!(dispositions, vec![Disposition::Pass, Disposition::Untested]);
+        let rendered: String = dispositions.iter().map(|x| x.to_string()).collect();
+        assert_eq!(rendered, "🟢⚪");
+    }
+
+    fn fallible_generator(input: &str) -> Result<Vec<u32>> {
+        input
+            .lines()
+            .map(|x| {
+                x.parse()
+                    .map_err(|_| Error::at(input, x, "expected a number"))
+            })
+            .collect()
+    }
+
+    fn fallible_part2(input: &[u32]) -> Result<u32> {
+        input
+            .iter()
+            .max()
+            .copied()
+            .ok_or_else(|| Error::new("no numbers"))
+    }
+
+    #[test]
+    fn test_fallible() {
+        let mut registry = Registry::new();
+        registry
+            .day(2022, 1)
+            .part1(fallible_generator, part1)
+            .part2(fallible_generator, fallible_part2)
+            .examples(vec![Example::new("1\nx\n").part1(1).part2(1)]);
+
+        let dp = DayPart {
+            year: 2022,
+            day: 1,
+            part: 1,
+        };
+        let solution = registry.get(&dp).unwrap();
+        assert_eq!(solution.run("1\n2\n3").unwrap().answer, "6");
+        let error = solution.run("1\nx").unwrap_err();
+        assert_eq!((error.year, error.day), (Some(2022), Some(1)));
+        assert_eq!(error.position.unwrap().line, 2);
+
+        let dp = DayPart { part: 2, ..dp };
+        let solution = registry.get(&dp).unwrap();
+        assert_eq!(solution.run("1\n2\n3").unwrap().answer, "3");
+        assert_eq!(solution.run("").unwrap_err().message, "no numbers");
+
+        let dispositions = registry.test_examples(&dp);
+        assert!(matches!(dispositions[0], Disposition::Error(_)));
+        assert!(dispositions[0].is_failure());
+    }
+
+    #[test]
+    fn test_select() {
+        let mut registry = Registry::new();
+        registry.day(2021, 6).part1(str::to_owned, part2);
+        registry.day(2022, 6).part1(str::to_owned, part2);
+        registry.day(2022, 7).part1(str::to_owned, part2);
+
+        assert_eq!(registry.years(), vec![2021, 2022]);
+        assert_eq!(registry.select(Some(2022), None, None).count(), 2);
+        assert_eq!(registry.select(None, Some(6), None).count(), 2);
+        assert_eq!(registry.select(Some(2021), Some(7), None).count(), 0);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::input::Inputs;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The source folder of this repository.
+pub fn default_src() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// The module a new day starts out as.  It registers no parts, only its
+/// examples, so the runner leaves the day out until part 1 is added.
+pub fn template() -> &'static str {
+    r#"use crate::registry::Day;
+
+pub fn register(day: &mut Day) {
+    // Add `.part1(generator, part1)` here once part 1 is solved.
+    day.examples(examples());
+}
+
+crate::examples! {}
+"#
+}
+
+/// A file [`scaffold`] wrote, and whether it was created or edited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub path: PathBuf,
+    pub created: bool,
+}
+
+/// Adds a day to the source tree under `src`: the day module from
+/// [`template`], its `pub mod` line and registration in the year module,
+/// the year module itself if this is the first day of the year, and an
+/// empty input.  Nothing is written unless the day is new, existing inputs
+/// are left alone.
+pub fn scaffold(
+    src: &Path,
+    inputs: &Inputs,
+    year: u32,
+    day: u32,
+) -> Result<Vec<Change>, ScaffoldError> {
+    let year_dir = src.join(format!("year{}", year));
+    let module = year_dir.join(format!("day{}.rs", day));
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+
+    // Work out every edit before writing any of them, so a tree the edits
+    // do not fit is left as it was.
+    let mut writes = Vec::new();
+    let year_mod = year_dir.join("mod.rs");
+    if year_mod.exists() {
+        let text = read(&year_mod)?;
+        let registration = format!(
+            "    day{}::register(&mut registry.day({}, {}));",
+            day, year, day
+        );
+        let edited = add_module(&text, "day", day, "pub fn register(", registration)
+            .ok_or_else(|| ScaffoldError::Unrecognized(year_mod.clone()))?;
+        writes.push((year_mod, edited, false));
+    } else {
+        let lib = src.join("lib.rs");
+        let text = read(&lib)?;
+        let registration = format!("    year{}::register(&mut registry);", year);
+        let edited = add_module(&text, "year", year, "pub fn registry(", registration)
+            .ok_or_else(|| ScaffoldError::Unrecognized(lib.clone()))?;
+        writes.push((lib, edited, false));
+        let text = format!(
+            "use crate::registry::Registry;\n\n\
+             pub mod day{day};\n\n\
+             pub fn register(registry: &mut Registry) {{\n    \
+             day{day}::register(&mut registry.day({year}, {day}));\n\
+             }}\n",
+            year = year,
+            day = day
+        );
+        writes.push((year_mod, text, true));
+    }
+    writes.push((module, template().to_owned(), true));
+
+    let input = inputs.path(year, day);
+    if !input.exists() {
+        writes.push((input, String::new(), true));
+    }
+
+    let mut changes = Vec::new();
+    for (path, text, created) in writes {
+        write(&path, &text)?;
+        changes.push(Change { path, created });
+    }
+    Ok(changes)
+}
+
+// Adds `pub mod <kind><number>;` where rustfmt would sort it and its
+// registration in numeric order among the other `<kind><n>::register` calls
+// in `function`, the way the module lists are kept by hand.  `None` if the
+// module is declared already or `function` is not there.
+fn add_module(
+    text: &str,
+    kind: &str,
+    number: u32,
+    function: &str,
+    registration: String,
+) -> Option<String> {
+    let name = format!("{}{}", kind, number);
+    let mut lines: Vec<String> = text.lines().map(str::to_owned).collect();
+    let declared = |line: &str| -> Option<String> {
+        let rest = line.strip_prefix("pub mod ")?;
+        Some(rest.strip_suffix(';')?.to_owned())
+    };
+    let mods: Vec<(usize, String)> = (0..lines.len())
+        .filter_map(|idx| declared(&lines[idx]).map(|x| (idx, x)))
+        .collect();
+    if mods.iter().any(|(_, x)| *x == name) {
+        return None;
+    }
+
+    let at = mods
+        .iter()
+        .find(|(_, x)| *x > name)
+        .map(|&(idx, _)| idx)
+        .or_else(|| mods.last().map(|&(idx, _)| idx + 1))?;
+    lines.insert(at, format!("pub mod {};", name));
+
+    let body = lines.iter().position(|x| x.starts_with(function))?;
+    let end = body + lines[body..].iter().position(|x| x == "}")?;
+    let registered = |line: &str| -> Option<u32> {
+        let rest = line.trim_start().strip_prefix(kind)?;
+        rest.split_once("::register(")?.0.parse().ok()
+    };
+    let calls: Vec<(usize, u32)> = (body + 1..end)
+        .filter_map(|idx| registered(&lines[idx]).map(|n| (idx, n)))
+        .collect();
+    let at = calls
+        .iter()
+        .find(|&&(_, n)| n > number)
+        .map(|&(idx, _)| idx)
+        .or_else(|| calls.last().map(|&(idx, _)| idx + 1))
+        .unwrap_or(body + 1);
+    lines.insert(at, registration);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Some(text)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn write(path: &Path, text: &str) -> Result<(), ScaffoldError> {
+    let io = |source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io)?;
+    }
+    std::fs::write(path, text).map_err(io)
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Unrecognized(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "{} exists already", path.display()),
+            Self::Unrecognized(path) => write!(
+                f,
+                "could not add the day to {}, it is declared already or has no register function",
+                path.display()
+            ),
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::site::stand_in::scratch;
+
+    const YEAR: &str = "use crate::registry::Registry;
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+pub fn register(registry: &mut Registry) {
+    day1::register(&mut registry.day(2022, 1));
+    day2::register(&mut registry.day(2022, 2));
+    day10::register(&mut registry.day(2022, 10));
+}
+";
+
+    #[test]
+    fn test_add_module() {
+        let add = |n| {
+            let registration = format!("    day{}::register(&mut registry.day(2022, {}));", n, n);
+            add_module(YEAR, "day", n, "pub fn register(", registration)
+        };
+        let edited = add(3).unwrap();
+        assert_eq!(
+            edited,
+            YEAR.replace("pub mod day2;\n", "pub mod day2;\npub mod day3;\n")
+                .replace(
+                    "2));\n",
+                    "2));\n    day3::register(&mut registry.day(2022, 3));\n"
+                )
+        );
+
+        let edited = add(11).unwrap();
+        assert!(edited.contains("pub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(edited.contains("(2022, 10));\n    day11::register"));
+        assert_eq!(add(2), None);
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = scratch("scaffold");
+        let src = root.join("src");
+        std::fs::create_dir_all(src.join("year2022")).unwrap();
+        std::fs::write(src.join("year2022/mod.rs"), YEAR).unwrap();
+        std::fs::write(
+            src.join("lib.rs"),
+            "pub mod bench;\npub mod year2022;\n\npub fn registry() -> Registry {\n    \
+             let mut registry = Registry::new();\n    \
+             year2022::register(&mut registry);\n    registry\n}\n",
+        )
+        .unwrap();
+        let inputs = Inputs::new(root.join("input"));
+
+        let changes = scaffold(&src, &inputs, 2022, 3).unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(
+            std::fs::read_to_string(src.join("year2022/day3.rs")).unwrap(),
+            template()
+        );
+        assert_eq!(inputs.read(2022, 3).unwrap(), "");
+        let error = scaffold(&src, &inputs, 2022, 3).unwrap_err();
+        assert!(matches!(error, ScaffoldError::Exists(_)));
+
+        scaffold(&src, &inputs, 2023, 1).unwrap();
+        let lib = std::fs::read_to_string(src.join("lib.rs")).unwrap();
+        assert!(lib.contains("pub mod year2022;\npub mod year2023;\n"));
+        assert!(lib.contains("year2023::register(&mut registry);\n    registry"));
+        let year = std::fs::read_to_string(src.join("year2023/mod.rs")).unwrap();
+        assert!(year.contains("pub mod day1;\n"));
+        assert!(year.contains("day1::register(&mut registry.day(2023, 1));"));
+    }
+}
@@ -0,0 +1,287 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Something to search through, described by the moves out of each state.
+/// Grids, mazes and puzzle positions all fit as long as their states can be
+/// hashed.
+pub trait Graph {
+    type State: Clone + Eq + Hash;
+
+    /// The states one move away from `state`, each with the cost of the
+    /// move.  Searches that do not care about cost count every move as 1.
+    fn neighbors(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, u64)>;
+}
+
+/// A graph whose moves come from a closure, see [`from_fn`].
+pub struct FromFn<S, F> {
+    neighbors: F,
+    state: PhantomData<fn(&S)>,
+}
+
+/// Builds a graph from a closure returning the moves out of a state, for
+/// searches that do not need a type of their own.
+pub fn from_fn<S, I, F>(neighbors: F) -> FromFn<S, F>
+where
+    F: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    FromFn {
+        neighbors,
+        state: PhantomData,
+    }
+}
+
+impl<S, I, F> Graph for FromFn<S, F>
+where
+    S: Clone + Eq + Hash,
+    F: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    type State = S;
+
+    fn neighbors(&self, state: &S) -> impl IntoIterator<Item = (S, u64)> {
+        (self.neighbors)(state)
+    }
+}
+
+/// A route through a graph, both ends included, and what it cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub states: Vec<S>,
+    pub cost: u64,
+}
+
+// Every state a search has reached, with the cheapest known way there.
+// States are numbered in the order they are found so the bookkeeping can
+// live in plain vectors.
+struct Visited<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    cost: Vec<u64>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        Self {
+            states: vec![start.clone()],
+            index: HashMap::from([(start, 0)]),
+            cost: vec![0],
+            parent: vec![None],
+        }
+    }
+
+    // Records reaching `state` from `parent` for `cost`, returning its
+    // number if that is new or cheaper than before.
+    fn relax(&mut self, state: S, cost: u64, parent: usize) -> Option<usize> {
+        match self.index.get(&state) {
+            Some(&idx) if self.cost[idx] <= cost => None,
+            Some(&idx) => {
+                self.cost[idx] = cost;
+                self.parent[idx] = Some(parent);
+                Some(idx)
+            }
+            None => {
+                let idx = self.states.len();
+                self.index.insert(state.clone(), idx);
+                self.states.push(state);
+                self.cost.push(cost);
+                self.parent.push(Some(parent));
+                Some(idx)
+            }
+        }
+    }
+
+    fn path(&self, mut idx: usize) -> Path<S> {
+        let cost = self.cost[idx];
+        let mut states = vec![self.states[idx].clone()];
+        while let Some(parent) = self.parent[idx] {
+            states.push(self.states[parent].clone());
+            idx = parent;
+        }
+        states.reverse();
+        Path { states, cost }
+    }
+}
+
+/// Finds a route with the fewest moves to a state `is_goal` accepts,
+/// ignoring move costs.  The path cost is the number of moves.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::State,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(idx) = queue.pop_front() {
+        if is_goal(&visited.states[idx]) {
+            return Some(visited.path(idx));
+        }
+
+        let state = visited.states[idx].clone();
+        let cost = visited.cost[idx] + 1;
+        for (next, _) in graph.neighbors(&state) {
+            if !visited.index.contains_key(&next) {
+                queue.extend(visited.relax(next, cost, idx));
+            }
+        }
+    }
+    None
+}
+
+/// Finds the cheapest route to a state `is_goal` accepts.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Finds the cheapest route to a state `is_goal` accepts, trying the states
+/// `heuristic` rates closest first.  The heuristic must never overestimate
+/// the remaining cost, or the route found may not be the cheapest.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::State,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+    heuristic: impl Fn(&G::State) -> u64,
+) -> Option<Path<G::State>> {
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        if cost > visited.cost[idx] {
+            // Already reached more cheaply since this was queued.
+            continue;
+        }
+        if is_goal(&visited.states[idx]) {
+            return Some(visited.path(idx));
+        }
+
+        let state = visited.states[idx].clone();
+        for (next, step) in graph.neighbors(&state) {
+            let next_cost = cost + step;
+            if let Some(next_idx) = visited.relax(next, next_cost, idx) {
+                let estimate = next_cost + heuristic(&visited.states[next_idx]);
+                queue.push(Reverse((estimate, next_cost, next_idx)));
+            }
+        }
+    }
+    None
+}
+
+/// Every state reachable from `start`, `start` included, in the order they
+/// are found.
+pub fn flood_fill<G: Graph>(graph: &G, start: G::State) -> Vec<G::State> {
+    let mut seen = HashSet::from([start.clone()]);
+    let mut found = vec![start];
+    let mut idx = 0;
+    while idx < found.len() {
+        let state = found[idx].clone();
+        for (next, _) in graph.neighbors(&state) {
+            if seen.insert(next.clone()) {
+                found.push(next);
+            }
+        }
+        idx += 1;
+    }
+    found
+}
+
+/// Splits `states` into groups that can reach each other, flood filling
+/// from each state not grouped yet.  Moves are expected to go both ways.
+pub fn components<G: Graph>(
+    graph: &G,
+    states: impl IntoIterator<Item = G::State>,
+) -> Vec<Vec<G::State>> {
+    let mut grouped = HashSet::new();
+    let mut components = Vec::new();
+    for state in states {
+        if grouped.contains(&state) {
+            continue;
+        }
+        let component = flood_fill(graph, state);
+        grouped.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::Grid;
+
+    // The chiton cave from 2021 day 15, entering a cell costs its digit.
+    const CAVE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    fn cave() -> Grid<u64> {
+        Grid::parse(CAVE, |c| c.to_digit(10).map(u64::from)).unwrap()
+    }
+
+    #[test]
+    fn test_weighted() {
+        let grid = cave();
+        let graph = from_fn(|&(row, col): &(usize, usize)| {
+            grid.neighbors4(row, col)
+                .map(|x| (x, grid[x]))
+                .collect::<Vec<_>>()
+        });
+        let goal = (grid.height() - 1, grid.width() - 1);
+
+        let path = dijkstra(&graph, (0, 0), |&x| x == goal).unwrap();
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&goal));
+        let total: u64 = path.states[1..].iter().map(|&x| grid[x]).sum();
+        assert_eq!(total, 40);
+
+        let manhattan = |&(row, col): &(usize, usize)| (goal.0 - row + goal.1 - col) as u64;
+        let path = astar(&graph, (0, 0), |&x| x == goal, manhattan).unwrap();
+        assert_eq!(path.cost, 40);
+    }
+
+    #[test]
+    fn test_unweighted() {
+        let grid = Grid::parse("S.#.\n.##.\n...E", Some).unwrap();
+        let graph = from_fn(|&(row, col): &(usize, usize)| {
+            grid.neighbors4(row, col)
+                .filter(|&x| grid[x] != '#')
+                .map(|x| (x, 1))
+                .collect::<Vec<_>>()
+        });
+
+        let path = bfs(&graph, (0, 0), |&x| grid[x] == 'E').unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(bfs(&graph, (0, 0), |&x| x == (0, 2)), None);
+        assert_eq!(flood_fill(&graph, (0, 0)).len(), 9);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = Grid::parse("##..#\n#...#\n..#..", Some).unwrap();
+        let graph = from_fn(|&(row, col): &(usize, usize)| {
+            grid.neighbors4(row, col)
+                .filter(|&x| grid[x] == '#')
+                .map(|x| (x, 1))
+                .collect::<Vec<_>>()
+        });
+
+        let walls = grid.positions().filter(|&x| grid[x] == '#');
+        let mut sizes: Vec<usize> = components(&graph, walls).iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, [1, 2, 3]);
+    }
+}
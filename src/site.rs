@@ -0,0 +1,460 @@
+use crate::input::Inputs;
+use crate::parse::Text;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where puzzles are downloaded from unless the config says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie, it wins over the one
+/// in the config file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The config file next to the inputs.  It holds the session cookie, so it
+/// is kept out of git.
+pub fn default_config_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.conf")
+}
+
+/// How to reach the puzzle site, read from `key: value` lines.  `session`
+/// is the value of the `session` cookie of a logged in browser and
+/// `base_url` points somewhere other than the real site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config from disk, a missing file is the default config.
+    /// The `AOC_SESSION` environment variable is applied on top.
+    pub fn load(path: &Path) -> Result<Self, SiteError> {
+        let mut config = match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(source) => {
+                return Err(SiteError::Read {
+                    path: path.to_owned(),
+                    source,
+                })
+            }
+        };
+        if let Some(session) = std::env::var(SESSION_ENV).ok().filter(|x| !x.is_empty()) {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, SiteError> {
+        let mut config = Self::default();
+        let records = Text::new(text)
+            .lines()
+            .filter(|x| !x.as_str().starts_with('#'))
+            .filter(|x| !x.trim().is_empty())
+            .map(|x| x.record());
+        for record in records {
+            let (key, value) = record.map_err(SiteError::Config)?;
+            match key.as_str() {
+                "session" => config.session = Some(value.as_str().to_owned()),
+                "base_url" => config.base_url = value.as_str().trim_end_matches('/').to_owned(),
+                _ => return Err(SiteError::Config(key.error("unknown setting"))),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Talks to the puzzle site on behalf of one config.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+/// A file [`Client::fetch`] made sure of, and whether it was already there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub cached: bool,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!(
+                "advent-of-code/",
+                env!("CARGO_PKG_VERSION"),
+                " (",
+                env!("CARGO_PKG_AUTHORS"),
+                ")"
+            ))
+            .build();
+        Self { config, agent }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url, path)
+    }
+
+    fn session(&self) -> Result<&str, SiteError> {
+        self.config.session.as_deref().ok_or(SiteError::NoSession)
+    }
+
+    /// Downloads a page, sending the session cookie along.
+    pub fn get(&self, path: &str) -> Result<String, SiteError> {
+        let url = self.url(path);
+        let request = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session()?));
+        read_response(&url, request.call())
+    }
+
+    /// Posts a form, sending the session cookie along.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, SiteError> {
+        let url = self.url(path);
+        let request = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session()?));
+        read_response(&url, request.send_form(form))
+    }
+
+    /// Makes sure the input and the example blocks of a day are on disk,
+    /// downloading whatever is missing.  Files that exist are never fetched
+    /// again, the site asks for inputs not to be downloaded twice.
+    pub fn fetch(&self, inputs: &Inputs, year: u32, day: u32) -> Result<Vec<Fetched>, SiteError> {
+        let mut fetched = Vec::new();
+        // An empty input is the placeholder `new` leaves behind.
+        let path = inputs.path(year, day);
+        if path.metadata().is_ok_and(|x| x.len() > 0) {
+            fetched.push(Fetched { path, cached: true });
+        } else {
+            let input = self.get(&format!("/{}/day/{}/input", year, day))?;
+            write(&path, &input)?;
+            fetched.push(Fetched {
+                path,
+                cached: false,
+            });
+        }
+
+        // The examples are cut from the puzzle page, which only has to be
+        // downloaded once.  How many there were is noted afterwards, even
+        // when there were none.
+        let count = inputs.example_count_path(year, day);
+        if count.exists() || inputs.example_path(year, day, 1).exists() {
+            fetched.extend(
+                (1..)
+                    .map(|n| inputs.example_path(year, day, n))
+                    .take_while(|x| x.exists())
+                    .map(|path| Fetched { path, cached: true }),
+            );
+        } else {
+            let page = self.get(&format!("/{}/day/{}", year, day))?;
+            let examples = examples(&page);
+            for (idx, example) in examples.iter().enumerate() {
+                let path = inputs.example_path(year, day, idx + 1);
+                write(&path, example)?;
+                fetched.push(Fetched {
+                    path,
+                    cached: false,
+                });
+            }
+            write(&count, &format!("{}\n", examples.len()))?;
+        }
+        Ok(fetched)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, SiteError> {
+    match response {
+        Ok(x) => x.into_string().map_err(|e| SiteError::Transport {
+            url: url.to_owned(),
+            message: format!("{}: {}", url, e),
+        }),
+        Err(ureq::Error::Status(status, _)) => Err(SiteError::Http {
+            url: url.to_owned(),
+            status,
+        }),
+        // Transport errors already name the url.
+        Err(ureq::Error::Transport(e)) => Err(SiteError::Transport {
+            url: url.to_owned(),
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn write(path: &Path, text: &str) -> Result<(), SiteError> {
+    let io = |source| SiteError::Write {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io)?;
+    }
+    std::fs::write(path, text).map_err(io)
+}
+
+/// The `<pre><code>` blocks of a puzzle page, with the markup inside them
+/// removed.  Puzzle pages put their examples in those and nothing else.
+pub fn examples(page: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<pre><code>") {
+        rest = &rest[start + "<pre><code>".len()..];
+        let end = rest.find("</code></pre>").unwrap_or(rest.len());
+        examples.push(unescape(&strip_tags(&rest[..end])));
+        rest = &rest[end..];
+    }
+    examples
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+fn unescape(text: &str) -> String {
+    // `&amp;` goes last so an escaped entity is not unescaped twice.
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[derive(Debug)]
+pub enum SiteError {
+    NoSession,
+    Config(crate::error::Error),
+    Http { url: String, status: u16 },
+    Transport { url: String, message: String },
+    Read { path: PathBuf, source: io::Error },
+    Write { path: PathBuf, source: io::Error },
+}
+
+impl Display for SiteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSession => write!(
+                f,
+                "no session cookie, set `session` in {} or {}",
+                default_config_path().display(),
+                SESSION_ENV
+            ),
+            Self::Config(e) => write!(f, "bad config, {}", e),
+            Self::Http { url, status } => write!(f, "{} answered with status {}", url, status),
+            Self::Transport { message, .. } => write!(f, "failed to reach {}", message),
+            Self::Read { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            Self::Write { path, source } => {
+                write!(f, "failed to write {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SiteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read { source, .. } | Self::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A tiny HTTP server standing in for the puzzle site in tests.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    /// A request the stand-in received.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    pub struct StandIn {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    /// Serves `respond`'s status and body for every request, on a port of
+    /// its own.
+    pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut words = line.split_whitespace();
+                let method = words.next().unwrap_or_default().to_owned();
+                let path = words.next().unwrap_or_default().to_owned();
+
+                let mut cookie = None;
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(':').unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = Some(value.trim().to_owned()),
+                        "content-length" => length = value.trim().parse().unwrap(),
+                        _ => (),
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request = Request {
+                    method,
+                    path,
+                    cookie,
+                    body: String::from_utf8(body).unwrap(),
+                };
+                let (status, body) = respond(&request);
+                log.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        StandIn { base_url, requests }
+    }
+
+    /// An empty folder to write into, unique to the test.
+    pub fn scratch(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        path
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::stand_in::{scratch, start};
+    use super::*;
+
+    const PAGE: &str = "<article><p>For example:</p>\n\
+        <pre><code>1000\n2000\n\n<em>3000</em>\n</code></pre>\n\
+        <p>Then:</p><pre><code>a -&gt; b &amp;&amp; c\n</code></pre></article>";
+
+    #[test]
+    fn test_config() {
+        let config =
+            Config::parse("# comment\nsession: abc123\nbase_url: http://localhost:1/\n").unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:1");
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+
+        let error = Config::parse("session: x\ncolour: blue").unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("line 2, column 1: unknown setting"));
+
+        // A folder where the file should be cannot be read.
+        let error = Config::load(&scratch("config")).unwrap_err();
+        assert!(error.to_string().starts_with("failed to read"));
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(examples(PAGE), ["1000\n2000\n\n3000\n", "a -> b && c\n"]);
+    }
+
+    #[test]
+    fn test_fetch() {
+        let server = start(|request| match request.path.as_str() {
+            "/2022/day/1/input" => (200, "1\n2\n".to_owned()),
+            "/2022/day/1" => (200, PAGE.to_owned()),
+            "/2022/day/4" => (200, "<article>No examples</article>".to_owned()),
+            _ => (404, String::new()),
+        });
+        let client = Client::new(Config {
+            session: Some("secret".to_owned()),
+            base_url: server.base_url.clone(),
+        });
+        let root = scratch("fetch");
+        let inputs = Inputs::new(&root);
+
+        let fetched = client.fetch(&inputs, 2022, 1).unwrap();
+        assert_eq!(fetched.len(), 3);
+        assert!(fetched.iter().all(|x| !x.cached));
+        assert_eq!(inputs.read(2022, 1).unwrap(), "1\n2");
+        let example = std::fs::read_to_string(inputs.example_path(2022, 1, 2)).unwrap();
+        assert_eq!(example, "a -> b && c\n");
+        {
+            let requests = server.requests.lock().unwrap();
+            assert_eq!(requests.len(), 2);
+            assert!(requests
+                .iter()
+                .all(|x| x.method == "GET" && x.body.is_empty()));
+            assert!(requests
+                .iter()
+                .all(|x| x.cookie.as_deref() == Some("session=secret")));
+        }
+
+        // Everything is on disk now, the site is not asked again.
+        let fetched = client.fetch(&inputs, 2022, 1).unwrap();
+        assert_eq!(fetched.len(), 3);
+        assert!(fetched.iter().all(|x| x.cached));
+        assert_eq!(server.requests.lock().unwrap().len(), 2);
+
+        // A page without examples is only fetched once too.
+        std::fs::write(inputs.path(2022, 4), "x").unwrap();
+        for _ in 0..2 {
+            let fetched = client.fetch(&inputs, 2022, 4).unwrap();
+            assert_eq!(fetched.len(), 1);
+        }
+        assert_eq!(server.requests.lock().unwrap().len(), 3);
+
+        let error = client.fetch(&inputs, 2022, 2).unwrap_err();
+        assert!(matches!(error, SiteError::Http { status: 404, .. }));
+
+        let anonymous = Client::new(Config::default());
+        let error = anonymous.fetch(&inputs, 2022, 3).unwrap_err();
+        assert!(matches!(error, SiteError::NoSession));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::error::Result;
+use crate::registry::{check_examples, Answer, Example};
+
+/// Solves one part of a [`Solution`] from its parsed input.
+pub type PartFn<S> = fn(&<S as Solution>::Parsed) -> Result<Answer>;
+
+/// A day written as one type instead of loose functions, the way the
+/// python `Day` class declares one: a parser, part 1, and part 2 and the
+/// examples once there are any.  Register it with
+/// [`Day::solution`](crate::registry::Day::solution).
+///
+/// Parts hand back an [`Answer`], anything printable or a
+/// [`Bitmap`](crate::bitmap::Bitmap) becomes one through
+/// [`IntoAnswer`](crate::registry::IntoAnswer).
+pub trait Solution: 'static {
+    /// The parsed input both parts work from.
+    type Parsed: 'static;
+
+    /// Part 2, once it is solved.  Days still on part 1 leave it out, like
+    /// a python day without `@day.part2`.
+    const PART2: Option<PartFn<Self>> = None;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn examples() -> Vec<Example> {
+        Vec::new()
+    }
+}
+
+/// Runs every example of `S` through the registry, panicking on the first
+/// one that does not produce its expected answer.  A day's tests only need
+/// to call this.
+pub fn check<S: Solution>() {
+    for part in [1, 2] {
+        check_examples(
+            |day| {
+                day.solution::<S>();
+            },
+            part,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::{DayPart, Disposition, IntoAnswer, Registry};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            crate::parse::Text::new(input).numbers()
+        }
+
+        fn part1(parsed: &Vec<u32>) -> Result<Answer> {
+            parsed.iter().sum::<u32>().into_answer()
+        }
+
+        fn examples() -> Vec<Example> {
+            vec![Example::new("1 2 3").part1(6), Example::new("4").part1(4)]
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        check::<Sum>();
+
+        let mut registry = Registry::new();
+        registry.day(2022, 1).solution::<Sum>();
+        let dp = DayPart {
+            year: 2022,
+            day: 1,
+            part: 1,
+        };
+        let outcome = registry.get(&dp).unwrap().run("10, 20").unwrap();
+        assert_eq!(outcome.answer, "30");
+        assert_eq!(
+            registry.test_examples(&dp),
+            [Disposition::Pass, Disposition::Pass]
+        );
+
+        // Part 2 was left out, so it is not registered at all.
+        assert_eq!(registry.select(None, None, Some(2)).count(), 0);
+
+        struct Product;
+
+        impl Solution for Product {
+            type Parsed = Vec<u32>;
+
+            const PART2: Option<PartFn<Self>> = Some(|x| x.iter().product::<u32>().into_answer());
+
+            fn parse(input: &str) -> Result<Vec<u32>> {
+                Sum::parse(input)
+            }
+
+            fn part1(parsed: &Vec<u32>) -> Result<Answer> {
+                Sum::part1(parsed)
+            }
+        }
+
+        let mut registry = Registry::new();
+        registry.day(2022, 1).solution::<Product>();
+        let dp = DayPart { part: 2, ..dp };
+        let outcome = registry.get(&dp).unwrap().run("2, 3, 4").unwrap();
+        assert_eq!(outcome.answer, "24");
+    }
+}
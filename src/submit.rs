@@ -0,0 +1,453 @@
+use crate::ledger::{escape, unescape};
+use crate::registry::DayPart;
+use crate::site::{Client, SiteError};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    Wrong,
+
+    // The site is still making us wait after the last answer, this one was
+    // not looked at.
+    TooSoon,
+
+    // The part was solved already, this one was not looked at either.
+    AlreadySolved,
+}
+
+impl Verdict {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Right => "right",
+            Self::TooHigh => "high",
+            Self::TooLow => "low",
+            Self::Wrong => "wrong",
+            Self::TooSoon => "soon",
+            Self::AlreadySolved => "solved",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "right" => Some(Self::Right),
+            "high" => Some(Self::TooHigh),
+            "low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Right => "the right answer",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "not the right answer",
+            Self::TooSoon => "submitted too soon",
+            Self::AlreadySolved => "for a part that is already solved",
+        })
+    }
+}
+
+/// The verdict read from the page the site answers a submission with, and
+/// how long it asks to wait before the next one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+/// Reads the page the site answers a submission with, `None` if it does
+/// not look like any answer the site is known to give.
+pub fn parse_response(page: &str) -> Option<Response> {
+    let verdict = if page.contains("That's the right answer") {
+        Verdict::Right
+    } else if page.contains("answer is too high") {
+        Verdict::TooHigh
+    } else if page.contains("answer is too low") {
+        Verdict::TooLow
+    } else if page.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        return None;
+    };
+    Some(Response {
+        verdict,
+        wait: parse_wait(page),
+    })
+}
+
+// Wrong answers come with "Please wait one minute before trying again." or
+// "Please wait 5 minutes ...", early ones with "You have 1m 5s left to
+// wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some(start) = page.find("You have ") {
+        let rest = &page[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+        let mut seconds = 0;
+        for word in rest[..end].split_whitespace() {
+            let unit = match word.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            seconds += word[..word.len() - 1].parse::<u64>().ok()? * unit;
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = page.find("Please wait ")?;
+    let rest = &page[start + "Please wait ".len()..];
+    let (count, rest) = rest.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        x => x.parse().ok()?,
+    };
+    rest.starts_with("minute")
+        .then(|| Duration::from_secs(count * 60))
+}
+
+/// A submitted answer and its verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub dp: DayPart,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Every answer submitted so far, kept so answers the site would reject
+/// are refused before they cost a wait.  Stored one per line as
+/// `<year> <day> <part> <verdict> <answer>`, or `<year> <day> <part> wait
+/// <unix time>` for when the site will take the next answer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    entries: Vec<Entry>,
+    wait_until: BTreeMap<DayPart, u64>,
+}
+
+/// The history kept next to the inputs.  It is personal, so it is kept out
+/// of git.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("submissions.txt")
+}
+
+fn unix(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl History {
+    /// Loads the history from disk, a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(SubmitError::Io(e)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, SubmitError> {
+        let mut history = Self::default();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || SubmitError::Malformed {
+                line: idx + 1,
+                text: line.to_owned(),
+            };
+            let fields: Vec<&str> = line.splitn(5, ' ').collect();
+            let [year, day, part, kind, value] = fields[..] else {
+                return Err(malformed());
+            };
+            let number = |x: &str| x.parse().map_err(|_| malformed());
+            let dp = DayPart {
+                year: number(year)?,
+                day: number(day)?,
+                part: number(part)?,
+            };
+            if kind == "wait" {
+                let until = value.parse().map_err(|_| malformed())?;
+                history.wait_until.insert(dp, until);
+            } else {
+                history.entries.push(Entry {
+                    dp,
+                    verdict: Verdict::from_name(kind).ok_or_else(malformed)?,
+                    answer: unescape(value),
+                });
+            }
+        }
+        Ok(history)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn entries(&self, dp: DayPart) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |x| x.dp == dp)
+    }
+
+    /// Why `answer` should not be submitted at `now`, if there is a reason.
+    /// Answers already judged, answers past one already too high or too
+    /// low, and answers the site would make us wait for are all refused.
+    pub fn check(&self, dp: DayPart, answer: &str, now: SystemTime) -> Option<String> {
+        if let Some(&until) = self.wait_until.get(&dp) {
+            if until > unix(now) {
+                return Some(format!(
+                    "the site asked to wait another {}s",
+                    until - unix(now)
+                ));
+            }
+        }
+
+        let number = answer.parse::<i128>().ok();
+        for entry in self.entries(dp) {
+            if entry.verdict == Verdict::Right {
+                return Some(format!("already solved with {}", entry.answer));
+            }
+            if entry.answer == answer {
+                return Some(format!("{} was already {}", answer, entry.verdict));
+            }
+
+            let bound = entry.answer.parse::<i128>().ok();
+            match (entry.verdict, number, bound) {
+                (Verdict::TooHigh, Some(x), Some(high)) if x >= high => {
+                    return Some(format!("{} is too high, {} already was", x, high))
+                }
+                (Verdict::TooLow, Some(x), Some(low)) if x <= low => {
+                    return Some(format!("{} is too low, {} already was", x, low))
+                }
+                _ => (),
+            }
+        }
+        None
+    }
+
+    /// Records what the site said about `answer` at `now`.
+    pub fn record(&mut self, dp: DayPart, answer: &str, response: Response, now: SystemTime) {
+        if matches!(
+            response.verdict,
+            Verdict::Right | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        ) {
+            self.entries.push(Entry {
+                dp,
+                verdict: response.verdict,
+                answer: answer.to_owned(),
+            });
+        }
+        if let Some(wait) = response.wait {
+            self.wait_until.insert(dp, unix(now) + wait.as_secs());
+        }
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# year day part verdict answer")?;
+        for x in self.entries.iter() {
+            writeln!(
+                f,
+                "{} {} {} {} {}",
+                x.dp.year,
+                x.dp.day,
+                x.dp.part,
+                x.verdict.name(),
+                escape(&x.answer)
+            )?;
+        }
+        for (dp, until) in self.wait_until.iter() {
+            writeln!(f, "{} {} {} wait {}", dp.year, dp.day, dp.part, until)?;
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the history already knows better, and records
+/// what the site said about it.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    dp: DayPart,
+    answer: &str,
+    now: SystemTime,
+) -> Result<Verdict, SubmitError> {
+    if let Some(reason) = history.check(dp, answer, now) {
+        return Err(SubmitError::Refused(reason));
+    }
+
+    let path = format!("/{}/day/{}/answer", dp.year, dp.day);
+    let level = dp.part.to_string();
+    let page = client
+        .post(&path, &[("level", &level), ("answer", answer)])
+        .map_err(SubmitError::Site)?;
+    let response = parse_response(&page).ok_or(SubmitError::Unrecognized)?;
+    history.record(dp, answer, response, now);
+    Ok(response.verdict)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(String),
+    Site(SiteError),
+    Unrecognized,
+    Io(io::Error),
+    Malformed { line: usize, text: String },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Refused(reason) => write!(f, "not submitted, {}", reason),
+            Self::Site(e) => write!(f, "{}", e),
+            Self::Unrecognized => {
+                write!(f, "the site answered with a page that was not understood")
+            }
+            Self::Io(e) => write!(f, "failed to read submission history: {}", e),
+            Self::Malformed { line, text } => {
+                write!(f, "malformed submission history line {}: {:?}", line, text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::site::stand_in::start;
+    use crate::site::Config;
+
+    const DP: DayPart = DayPart {
+        year: 2022,
+        day: 1,
+        part: 1,
+    };
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    fn response(verdict: Verdict) -> Response {
+        Response {
+            verdict,
+            wait: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let high = "<article><p>That's not the right answer; your answer is too high.  \
+            Please wait one minute before trying again.</p></article>";
+        assert_eq!(
+            parse_response(high),
+            Some(Response {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+
+        let soon = "<p>You gave an answer too recently; you have to wait after \
+            submitting an answer before trying again.  You have 1m 5s left to wait.</p>";
+        assert_eq!(
+            parse_response(soon),
+            Some(Response {
+                verdict: Verdict::TooSoon,
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+
+        let low = "That's not the right answer; your answer is too low.  Please wait 5 minutes";
+        assert_eq!(
+            parse_response(low).unwrap().wait,
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_response("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(response(Verdict::Right))
+        );
+        assert_eq!(parse_response("<html>Login</html>"), None);
+    }
+
+    #[test]
+    fn test_history() {
+        let mut history = History::default();
+        history.record(DP, "500", response(Verdict::TooHigh), at(0));
+        history.record(DP, "100", response(Verdict::TooLow), at(0));
+        history.record(DP, "abc", response(Verdict::Wrong), at(0));
+        let wait = Response {
+            verdict: Verdict::TooLow,
+            wait: Some(Duration::from_secs(60)),
+        };
+        history.record(DP, "200", wait, at(1000));
+
+        assert!(history.check(DP, "300", at(1030)).unwrap().contains("30s"));
+        assert_eq!(history.check(DP, "300", at(1060)), None);
+        assert_eq!(
+            history.check(DP, "600", at(2000)).unwrap(),
+            "600 is too high, 500 already was"
+        );
+        assert!(history.check(DP, "150", at(2000)).is_some());
+        assert_eq!(
+            history.check(DP, "abc", at(2000)).unwrap(),
+            "abc was already not the right answer"
+        );
+
+        let parsed = History::parse(&history.to_string()).unwrap();
+        assert_eq!(parsed, history);
+        history.record(DP, "300", response(Verdict::Right), at(2000));
+        assert!(history.check(DP, "300", at(2000)).is_some());
+        assert!(History::parse("2022 1 1 maybe 5").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = start(|request| {
+            let page = match request.body.as_str() {
+                "level=1&answer=42" => "That's the right answer!",
+                "level=1&answer=99" => "That's not the right answer; your answer is too high.",
+                _ => "Who knows",
+            };
+            (200, page.to_owned())
+        });
+        let client = Client::new(Config {
+            session: Some("secret".to_owned()),
+            base_url: server.base_url.clone(),
+        });
+        let mut history = History::default();
+
+        let verdict = submit(&client, &mut history, DP, "99", at(0)).unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+        let error = submit(&client, &mut history, DP, "100", at(0)).unwrap_err();
+        assert!(matches!(error, SubmitError::Refused(_)));
+        let error = submit(&client, &mut history, DP, "7", at(0)).unwrap_err();
+        assert!(matches!(error, SubmitError::Unrecognized));
+        let verdict = submit(&client, &mut history, DP, "42", at(0)).unwrap();
+        assert_eq!(verdict, Verdict::Right);
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests
+            .iter()
+            .all(|x| x.method == "POST" && x.path == "/2022/day/1/answer"));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+/// A plain text table with columns padded to line up, for pasting into chat
+/// or a terminal.  Columns are left aligned unless marked otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    header: Vec<String>,
+    right: Vec<bool>,
+    rows: Vec<Row>,
+}
+
+#[derive(Debug, Clone)]
+enum Row {
+    Cells(Vec<String>),
+    Rule,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Self {
+            header: header.iter().map(|x| x.to_string()).collect(),
+            right: vec![false; header.len()],
+            rows: Vec::new(),
+        }
+    }
+
+    /// Right aligns a column, for numbers and durations.
+    pub fn align_right(mut self, column: usize) -> Self {
+        self.right[column] = true;
+        self
+    }
+
+    /// Adds a row.  Missing trailing cells are left blank.
+    pub fn row(&mut self, cells: Vec<String>) -> &mut Self {
+        assert!(cells.len() <= self.header.len(), "row has too many cells");
+        self.rows.push(Row::Cells(cells));
+        self
+    }
+
+    /// Adds a horizontal rule, for setting off a totals row.
+    pub fn rule(&mut self) -> &mut Self {
+        self.rows.push(Row::Rule);
+        self
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.header.iter().map(|x| width(x)).collect();
+        for row in self.rows.iter() {
+            if let Row::Cells(cells) = row {
+                for (w, cell) in widths.iter_mut().zip(cells) {
+                    *w = (*w).max(width(cell));
+                }
+            }
+        }
+        widths
+    }
+
+    fn write_cells(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        cells: &[String],
+    ) -> std::fmt::Result {
+        let mut line = String::new();
+        for (idx, w) in widths.iter().enumerate() {
+            let cell = cells.get(idx).map(String::as_str).unwrap_or("");
+            let pad = " ".repeat(w - width(cell));
+            if idx > 0 {
+                line.push_str("  ");
+            }
+            if self.right[idx] {
+                line.push_str(&pad);
+                line.push_str(cell);
+            } else {
+                line.push_str(cell);
+                line.push_str(&pad);
+            }
+        }
+        writeln!(f, "{}", line.trim_end())
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+        let rule = "-".repeat(widths.iter().sum::<usize>() + 2 * widths.len().saturating_sub(1));
+        self.write_cells(f, &widths, &self.header)?;
+        writeln!(f, "{}", rule)?;
+        for row in self.rows.iter() {
+            match row {
+                Row::Cells(cells) => self.write_cells(f, &widths, cells)?,
+                Row::Rule => writeln!(f, "{}", rule)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// Terminals draw emoji two columns wide, the example dispositions would
+// throw the alignment off otherwise.
+fn width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x26AA | 0x26AB | 0x1F000.. => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut table = Table::new(&["Day", "Examples", "Answer"]).align_right(0);
+        table
+            .row(vec!["1".into(), "🟢🟢".into(), "67622".into()])
+            .row(vec!["10".into(), "🔴⚪".into(), "15120".into()])
+            .rule()
+            .row(vec!["Total".into()]);
+        assert_eq!(
+            table.to_string(),
+            "  Day  Examples  Answer
+-----------------------
+    1  🟢🟢      67622
+   10  🔴⚪      15120
+-----------------------
+Total
+"
+        );
+    }
+}
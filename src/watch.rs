@@ -0,0 +1,131 @@
+use crate::ledger::Ledger;
+use crate::registry::DayPart;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Notices when any of a set of files changes by polling when each was
+/// last modified.  A file appearing or disappearing counts as a change.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let modified = modified(&path);
+                    (path, modified)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.iter().map(|(path, _)| path)
+    }
+
+    /// Whether anything changed since the last call, or since the watcher
+    /// was made.
+    pub fn changed(&mut self) -> bool {
+        let mut changed = false;
+        for (path, last) in self.files.iter_mut() {
+            let now = modified(path);
+            if now != *last {
+                *last = now;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+fn modified(path: &std::path::Path) -> Option<SystemTime> {
+    path.metadata().and_then(|x| x.modified()).ok()
+}
+
+/// Describes how the answers of one run differ from the run before it, a
+/// line per part.  Multi-line answers list the lines that changed.
+pub fn compare(before: Option<&Ledger>, after: &Ledger) -> String {
+    let mut out = String::new();
+    for (dp, answer) in after.iter() {
+        let previous = before.and_then(|x| x.get(dp));
+        out.push_str(&describe(dp, previous, answer));
+    }
+    out
+}
+
+fn describe(dp: &DayPart, before: Option<&str>, after: &str) -> String {
+    match before {
+        None => format!("Part {}: {}\n", dp.part, after),
+        Some(x) if x == after => format!("Part {}: {} (unchanged)\n", dp.part, first_line(after)),
+        Some(x) if !x.contains('\n') && !after.contains('\n') => {
+            format!("Part {}: {} -> {}\n", dp.part, x, after)
+        }
+        Some(x) => {
+            let mut out = format!("Part {}: changed\n", dp.part);
+            let old: Vec<&str> = x.lines().collect();
+            let new: Vec<&str> = after.lines().collect();
+            for idx in 0..old.len().max(new.len()) {
+                match (old.get(idx), new.get(idx)) {
+                    (Some(a), Some(b)) if a == b => (),
+                    (a, b) => {
+                        if let Some(a) = a {
+                            out.push_str(&format!("  - {}\n", a));
+                        }
+                        if let Some(b) = b {
+                            out.push_str(&format!("  + {}\n", b));
+                        }
+                    }
+                }
+            }
+            out
+        }
+    }
+}
+
+fn first_line(answer: &str) -> String {
+    let mut lines = answer.lines();
+    let mut first = lines.next().unwrap_or_default().to_owned();
+    if lines.next().is_some() {
+        first.push_str(" …");
+    }
+    first
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::site::stand_in::scratch;
+
+    #[test]
+    fn test_compare() {
+        let before = Ledger::parse("2022 10 1 13140\n2022 10 2 ##..\\n..##\n").unwrap();
+        let after = Ledger::parse("2022 10 1 13140\n2022 10 2 ##..\\n.###\n").unwrap();
+        assert_eq!(
+            compare(Some(&before), &after),
+            "Part 1: 13140 (unchanged)\nPart 2: changed\n  - ..##\n  + .###\n"
+        );
+
+        let after = Ledger::parse("2022 10 1 13141\n2022 10 2 ##..\\n..##\n").unwrap();
+        assert_eq!(
+            compare(Some(&before), &after),
+            "Part 1: 13140 -> 13141\nPart 2: ##.. … (unchanged)\n"
+        );
+        assert_eq!(compare(None, &after).lines().next(), Some("Part 1: 13141"));
+    }
+
+    #[test]
+    fn test_watcher() {
+        let root = scratch("watch");
+        let path = root.join("day1.txt");
+        let mut watcher = Watcher::new([path.clone()]);
+        assert!(!watcher.changed());
+        std::fs::write(&path, "1").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::cycle::first_repeat;
+use crate::registry::Day;
+
+fn frequencies<'a>(input: &'a str) -> impl Iterator<Item = i32> + 'a + Clone {
+    input
+        .split_whitespace()
+        .map(|x| x.trim().parse::<i32>().unwrap())
+}
+
+fn part1(input: &str) -> i32 {
+    frequencies(input).sum()
+}
+
+fn part2(input: &str) -> i32 {
+    let totals = ::std::iter::once(0)
+        .chain(frequencies(input).cycle())
+        .scan(0, |counter, x| {
+            *counter += x;
+            Some(*counter)
+        });
+    let (_, frequency) = first_repeat(totals).unwrap();
+    frequency
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(str::to_owned, part1)
+        .part2(str::to_owned, part2)
+        .examples(examples());
+}
+
+crate::examples! {
+    "+1 +1 +1" => part1: 3;
+    "+1 +1 -2" => part1: 0;
+    "-1 -2 -3" => part1: -6;
+    "+1 -1" => part2: 0;
+    "+3 +3 +4 -2 -4" => part2: 10;
+    "-6 +3 +8 +5 -6" => part2: 5;
+    "+7 +7 -2 -7 -4" => part2: 14;
+}
@@ -0,0 +1,9 @@
+use crate::registry::Registry;
+
+pub mod day1;
+pub mod day2;
+
+pub fn register(registry: &mut Registry) {
+    day1::register(&mut registry.day(2018, 1));
+    day2::register(&mut registry.day(2018, 2));
+}
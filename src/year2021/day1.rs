@@ -1,18 +1,15 @@
-use aoc_runner_derive::aoc;
-use aoc_runner_derive::aoc_generator;
+use crate::registry::Day;
 use itertools::Itertools;
 
-#[aoc_generator(day1)]
 fn input_generation(input: &str) -> Vec<u32> {
     input
         .split("\n")
         .map(|s| s.trim())
-        .filter(|s| s.len() > 0)
+        .filter(|s| !s.is_empty())
         .map(|s| s.parse::<u32>().unwrap())
         .collect()
 }
 
-#[aoc(day1, part1)]
 fn part1(input: &[u32]) -> u32 {
     input
         .iter()
@@ -21,7 +18,6 @@ fn part1(input: &[u32]) -> u32 {
         .sum()
 }
 
-#[aoc(day1, part2)]
 fn part2(input: &[u32]) -> u32 {
     input
         .iter()
@@ -31,3 +27,8 @@ fn part2(input: &[u32]) -> u32 {
         .map(|(l, r)| if r > l { 1 } else { 0 })
         .sum()
 }
+
+pub fn register(day: &mut Day) {
+    day.part1(input_generation, part1)
+        .part2(input_generation, part2);
+}
@@ -1,4 +1,4 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::registry::Day;
 use itertools::Itertools;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -44,13 +44,13 @@ impl Position {
     }
 
     fn move_position(&mut self, act: &Action) {
-        match act {
-            &Action::Forward(x) => {
+        match *act {
+            Action::Forward(x) => {
                 self.forward += x;
                 self.depth += (x as i64) * self.aim
             }
-            &Action::Down(x) => self.aim += x as i64,
-            &Action::Up(x) => self.aim -= x as i64,
+            Action::Down(x) => self.aim += x as i64,
+            Action::Up(x) => self.aim -= x as i64,
         }
     }
 
@@ -64,17 +64,15 @@ impl Position {
     }
 }
 
-#[aoc_generator(day2)]
 fn input_generation(input: &str) -> Vec<Action> {
     input
         .split("\n")
         .map(|s| s.trim())
-        .filter(|s| s.len() > 0)
-        .map(|s| Action::parse_action_line(s))
+        .filter(|s| !s.is_empty())
+        .map(Action::parse_action_line)
         .collect()
 }
 
-#[aoc(day2, part1)]
 fn part1(input: &[Action]) -> i64 {
     let mut pos = Position::new();
 
@@ -85,7 +83,6 @@ fn part1(input: &[Action]) -> i64 {
     pos.answer_part1()
 }
 
-#[aoc(day2, part2)]
 fn part2(input: &[Action]) -> i64 {
     let mut pos = Position::new();
 
@@ -96,17 +93,27 @@ fn part2(input: &[Action]) -> i64 {
     pos.answer_part2()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+pub fn register(day: &mut Day) {
+    day.part1(input_generation, part1)
+        .part2(input_generation, part2)
+        .examples(examples());
+}
 
-    const INPUT_TEXT: &str = "forward 5
+const INPUT_TEXT: &str = "forward 5
 down 5
 forward 8
 up 3
 down 8
 forward 2";
 
+crate::examples! {
+    INPUT_TEXT => part1: 150, part2: 900;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     const INPUT_ACTIONS: [Action; 6] = [
         Action::Forward(5),
         Action::Down(5),
@@ -120,14 +127,4 @@ forward 2";
     fn test_generator() {
         assert_eq!(&input_generation(INPUT_TEXT), &INPUT_ACTIONS);
     }
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(&INPUT_ACTIONS), 150);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(&INPUT_ACTIONS), 900);
-    }
 }
@@ -1,4 +1,4 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::registry::Day;
 
 fn parse_binary(input: &str) -> Vec<u8> {
     input
@@ -11,12 +11,11 @@ fn parse_binary(input: &str) -> Vec<u8> {
         .collect()
 }
 
-#[aoc_generator(day3)]
 fn generator(input: &str) -> Vec<Vec<u8>> {
     input
         .split("\n")
         .map(|s| s.trim())
-        .filter(|s| s.len() > 0)
+        .filter(|s| !s.is_empty())
         .map(parse_binary)
         .collect()
 }
@@ -37,14 +36,10 @@ fn epsilon_from_meta(value: u64, thresh: usize) -> u8 {
     }
 }
 
-#[aoc(day3, part1)]
 fn part1(input: &[Vec<u8>]) -> i64 {
     let numlines = input.len();
     let buflen = input[0].len();
-    let mut buf: Vec<u64> = Vec::with_capacity(buflen);
-    for _ in 0..buflen {
-        buf.push(0);
-    }
+    let mut buf: Vec<u64> = vec![0; buflen];
 
     for line in input {
         for (idx, val) in line.iter().enumerate() {
@@ -65,7 +60,7 @@ fn part1(input: &[Vec<u8>]) -> i64 {
     let g_num = i64::from_str_radix(&gamma, 2).unwrap();
     let e_num = i64::from_str_radix(&epsilon, 2).unwrap();
 
-    return g_num * e_num;
+    g_num * e_num
 }
 
 fn find_indexed_rating(numbers: &[&Vec<u8>], index: usize) -> u32 {
@@ -73,7 +68,7 @@ fn find_indexed_rating(numbers: &[&Vec<u8>], index: usize) -> u32 {
     for number in numbers {
         rating += number[index] as u32;
     }
-    return rating;
+    rating
 }
 
 fn filter_indexed_rating<'a>(
@@ -82,13 +77,13 @@ fn filter_indexed_rating<'a>(
     index: usize,
 ) -> Vec<&'a Vec<u8>> {
     numbers
-        .into_iter()
-        .map(|x| *x) // I don't understand why this is neccesary. Why do we end up with &&Vec?
+        .iter()
+        .copied() // I don't understand why this is neccesary. Why do we end up with &&Vec?
         .filter(|x| x[index] == desired)
         .collect()
 }
 
-fn filter_rating<'a>(mut numbers: Vec<&'a Vec<u8>>, lsr: LifeSupportRating) -> &'a Vec<u8> {
+fn filter_rating(mut numbers: Vec<&Vec<u8>>, lsr: LifeSupportRating) -> &Vec<u8> {
     let mut idx = 0;
     loop {
         if numbers.len() == 1 {
@@ -109,15 +104,15 @@ enum LifeSupportRating {
 
 impl LifeSupportRating {
     fn get_desired_value(&self, meta: u32, count: usize) -> u8 {
-        match self {
-            &LifeSupportRating::Oxygen => {
+        match *self {
+            LifeSupportRating::Oxygen => {
                 if meta as f32 >= count as f32 / 2f32 {
                     1
                 } else {
                     0
                 }
             }
-            &LifeSupportRating::CO2 => {
+            LifeSupportRating::CO2 => {
                 if (meta as f32) < count as f32 / 2f32 {
                     1
                 } else {
@@ -128,7 +123,6 @@ impl LifeSupportRating {
     }
 }
 
-#[aoc(day3, part2)]
 fn part2(input: &[Vec<u8>]) -> i64 {
     // let numbers: HashSet<Vec<u8>> = HashSet::from(input.iter());
     let mut oxy_numbers = Vec::with_capacity(input.len());
@@ -146,14 +140,16 @@ fn part2(input: &[Vec<u8>]) -> i64 {
     let oxy = i64::from_str_radix(&oxy_str, 2).unwrap();
     let co2 = i64::from_str_radix(&co2_str, 2).unwrap();
 
-    return oxy * co2;
+    oxy * co2
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
 
-    const INPUT_TEXT: &str = "00100
+const INPUT_TEXT: &str = "00100
 11110
 10110
 10111
@@ -166,15 +162,6 @@ mod test {
 00010
 01010";
 
-    #[test]
-    fn tests_part1() {
-        let parsed = generator(INPUT_TEXT);
-        assert_eq!(part1(&parsed), 198);
-    }
-
-    #[test]
-    fn tests_part2() {
-        let parsed = generator(INPUT_TEXT);
-        assert_eq!(part2(&parsed), 230)
-    }
+crate::examples! {
+    INPUT_TEXT => part1: 198, part2: 230;
 }
@@ -1,5 +1,5 @@
-use aoc_runner_derive::{aoc, aoc_generator};
-use regex::Regex;
+use crate::parse::Text;
+use crate::registry::Day;
 use std::collections::HashSet;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -20,7 +20,7 @@ impl BingoBoard {
             .lines()
             .map(|line| {
                 line.split_ascii_whitespace()
-                    .map(|num| u32::from_str_radix(num, 10).unwrap())
+                    .map(|num| num.parse().unwrap())
                     .collect()
             })
             .collect();
@@ -89,7 +89,7 @@ fn find_solutions(board: &[Vec<u32>]) -> Vec<HashSet<u32>> {
     // topright.insert(board[0][4]);
     // solutions.push(topright);
 
-    return solutions;
+    solutions
 }
 
 #[derive(Debug)]
@@ -104,11 +104,9 @@ impl BingoGame {
     }
 }
 
-#[aoc_generator(day4)]
 fn generator(input: &str) -> BingoGame {
-    // Split objects in the input based on fresh newline
-    let re = Regex::new(r"\n\s*\n").unwrap();
-    let mut it = re.split(input).filter(|x| x.trim().len() > 0);
+    // Split objects in the input based on blank lines
+    let mut it = Text::new(input).blocks().map(|x| x.as_str());
     let drawings = it
         .next()
         .unwrap()
@@ -120,7 +118,6 @@ fn generator(input: &str) -> BingoGame {
     BingoGame::new(drawings, boards)
 }
 
-#[aoc(day4, part1)]
 fn part1(game: &BingoGame) -> u32 {
     let mut picked = HashSet::new();
     let mut number_picker = game.drawings.iter().cloned();
@@ -128,7 +125,7 @@ fn part1(game: &BingoGame) -> u32 {
         let last_number = number_picker.next().unwrap();
         picked.insert(last_number);
         let wins: Vec<_> = game.boards.iter().filter(|x| x.has_won(&picked)).collect();
-        if wins.len() == 0 {
+        if wins.is_empty() {
             // continue looking for a winner
             continue;
         }
@@ -143,7 +140,6 @@ fn part1(game: &BingoGame) -> u32 {
     }
 }
 
-#[aoc(day4, part2)]
 fn part2(game: &BingoGame) -> u32 {
     let mut picked = HashSet::new();
     let mut number_picker = game.drawings.iter().cloned();
@@ -155,7 +151,7 @@ fn part2(game: &BingoGame) -> u32 {
     let mut last_winners = Vec::new();
 
     assert!(remaining_games.len() > 1);
-    while remaining_games.len() > 0 {
+    while !remaining_games.is_empty() {
         last_number = number_picker.next().unwrap();
         picked.insert(last_number);
 
@@ -165,21 +161,19 @@ fn part2(game: &BingoGame) -> u32 {
             .filter(|x| x.has_won(&picked))
             .cloned()
             .collect();
-        remaining_games = remaining_games
-            .into_iter()
-            .filter(|x| !x.has_won(&picked))
-            .collect();
+        remaining_games.retain(|x| !x.has_won(&picked));
     }
 
-    return last_winners[0].partial_score(&picked) * last_number;
+    last_winners[0].partial_score(&picked) * last_number
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
 
-    const INPUT_TEXT: &str =
-        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+const INPUT_TEXT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
 22 13 17 11  0
  8  2 23  4 24
@@ -199,15 +193,6 @@ mod tests {
 22 11 13  6  5
  2  0 12  3  7";
 
-    #[test]
-    fn test_part1() {
-        let processed = generator(INPUT_TEXT);
-        assert_eq!(part1(&processed), 4512);
-    }
-
-    #[test]
-    fn test_part2() {
-        let processed = generator(INPUT_TEXT);
-        assert_eq!(part2(&processed), 1924);
-    }
+crate::examples! {
+    INPUT_TEXT => part1: 4512, part2: 1924;
 }
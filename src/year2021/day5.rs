@@ -0,0 +1,68 @@
+use crate::error::Result;
+use crate::geometry::{Line, Point};
+use crate::parse::Text;
+use crate::registry::Day;
+use std::collections::HashSet;
+
+fn generator(input: &str) -> Result<Vec<Line>> {
+    Text::new(input)
+        .lines()
+        .map(|line| {
+            let [x1, y1, x2, y2] = line.trim().scan("{},{} -> {},{}")?;
+            Ok(Line::new(
+                Point::new(x1.number()?, y1.number()?),
+                Point::new(x2.number()?, y2.number()?),
+            ))
+        })
+        .collect()
+}
+
+fn part1(input: &[Line]) -> u32 {
+    let mut points = HashSet::new();
+    let mut double_covered_points = HashSet::new();
+    for line in input.iter().filter(|l| l.is_cardinal()) {
+        for pos in line.points() {
+            if !points.insert(pos) {
+                // position already existed in the set
+                double_covered_points.insert(pos);
+            }
+        }
+    }
+
+    double_covered_points.len() as u32
+}
+
+fn part2(input: &[Line]) -> u32 {
+    let mut points = HashSet::new();
+    let mut double_covered_points = HashSet::new();
+    for line in input {
+        for pos in line.points() {
+            if !points.insert(pos) {
+                double_covered_points.insert(pos);
+            }
+        }
+    }
+
+    double_covered_points.len() as u32
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+crate::examples! {
+    INPUT_TEXT => part1: 5, part2: 12;
+}
@@ -1,7 +1,6 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::registry::Day;
 use std::collections::VecDeque;
 
-#[aoc_generator(day6)]
 fn generator(input: &str) -> Vec<u64> {
     let mut buf = vec![0u64; 9];
     for thing in input.split(',') {
@@ -9,7 +8,7 @@ fn generator(input: &str) -> Vec<u64> {
         buf[count] += 1;
     }
 
-    return buf;
+    buf
 }
 
 fn simulate(input: &[u64], days: u32) -> u64 {
@@ -26,34 +25,25 @@ fn simulate(input: &[u64], days: u32) -> u64 {
         *pos += latest;
     }
 
-    return deque.into_iter().sum();
+    deque.into_iter().sum()
 }
 
-#[aoc(day6, part1)]
 fn part1(input: &[u64]) -> u64 {
     simulate(input, 80)
 }
 
-#[aoc(day6, part2)]
 fn part2(input: &[u64]) -> u64 {
     simulate(input, 256)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT_TEXT: &'static str = "3,4,3,1,2";
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
 
-    #[test]
-    fn test_part1() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part1(&data), 5934);
-    }
+const INPUT_TEXT: &str = "3,4,3,1,2";
 
-    #[test]
-    fn test_part2() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part2(&data), 26984457539);
-    }
+crate::examples! {
+    INPUT_TEXT => part1: 5934, part2: 26984457539u64;
 }
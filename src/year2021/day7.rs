@@ -1,18 +1,16 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::registry::Day;
 use std::cmp;
 use std::collections::HashMap;
 
-#[aoc_generator(day7)]
 fn generator(input: &str) -> HashMap<i32, i32> {
     let mut map: HashMap<i32, i32> = Default::default();
     for pos in input.split(',').map(|c| c.parse().unwrap()) {
         let entry = map.entry(pos).or_insert(0);
         *entry += 1;
     }
-    return map;
+    map
 }
 
-#[aoc(day7, part1)]
 fn part1(input: &HashMap<i32, i32>) -> i32 {
     let mut lowest_gas = i32::MAX;
     for target in input.keys() {
@@ -22,10 +20,9 @@ fn part1(input: &HashMap<i32, i32>) -> i32 {
         }
         lowest_gas = cmp::min(lowest_gas, gas);
     }
-    return lowest_gas;
+    lowest_gas
 }
 
-#[aoc(day7, part2)]
 fn part2(input: &HashMap<i32, i32>) -> i32 {
     let mut lowest_gas = i32::MAX;
 
@@ -47,24 +44,17 @@ fn part2(input: &HashMap<i32, i32>) -> i32 {
         }
         lowest_gas = cmp::min(lowest_gas, gas);
     }
-    return lowest_gas;
+    lowest_gas
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT_TEXT: &'static str = "16,1,2,0,4,2,7,1,2,14";
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
 
-    #[test]
-    fn test_part1() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part1(&data), 37);
-    }
+const INPUT_TEXT: &str = "16,1,2,0,4,2,7,1,2,14";
 
-    #[test]
-    fn test_part2() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part2(&data), 168);
-    }
+crate::examples! {
+    INPUT_TEXT => part1: 37, part2: 168;
 }
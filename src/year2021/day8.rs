@@ -1,4 +1,4 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::registry::Day;
 use itertools::Itertools;
 use std::collections::BTreeSet;
 
@@ -17,14 +17,9 @@ fn parse_chars(input: &str) -> Vec<String> {
 }
 
 fn is_unique_digit(input: &str) -> bool {
-    match input.len() {
-        2..=4 => true,
-        7 => true,
-        _ => false,
-    }
+    matches!(input.len(), 2..=4 | 7)
 }
 
-#[aoc_generator(day8)]
 fn generator(input: &str) -> Vec<Entry> {
     input
         .lines()
@@ -38,7 +33,6 @@ fn generator(input: &str) -> Vec<Entry> {
         .collect()
 }
 
-#[aoc(day8, part1)]
 fn part1(input: &[Entry]) -> i32 {
     input
         .iter()
@@ -142,8 +136,7 @@ fn find_exactly_len(input: &Entry, len: usize) -> &str {
         .patterns
         .iter()
         .map(|x| x.as_str())
-        .filter(|x| x.len() == len)
-        .next()
+        .find(|x| x.len() == len)
         .unwrap()
 }
 
@@ -191,8 +184,7 @@ fn derive_mapping(input: &Entry) -> Mapping {
     let potential_069 = find_potential_len_set(input, 6);
     let exactly_6_set = potential_069
         .iter()
-        .filter(|e| !e.is_superset(&exactly_1_set))
-        .next()
+        .find(|e| !e.is_superset(&exactly_1_set))
         .unwrap();
 
     let exactly_c = exactly_1_set
@@ -203,58 +195,51 @@ fn derive_mapping(input: &Entry) -> Mapping {
 
     let exactly_f = exactly_1_set
         .iter()
-        .filter(|&&x| x != exactly_c)
-        .next()
+        .find(|&&x| x != exactly_c)
         .unwrap()
         .to_owned();
 
     let exactly_5_set = potential_235
         .iter()
-        .filter(|x| !x.contains(&exactly_c))
-        .next()
+        .find(|x| !x.contains(&exactly_c))
         .unwrap();
 
     let exactly_2_set = potential_235
         .iter()
-        .filter(|&x| x != exactly_5_set && x != &exactly_3_set)
-        .next()
+        .find(|&x| x != exactly_5_set && x != &exactly_3_set)
         .unwrap();
 
     let exactly_e = exactly_2_set
         .difference(exactly_5_set)
         .copied()
-        .filter(|&c| if c == exactly_c { false } else { true })
-        .next()
+        .find(|&c| c != exactly_c)
         .unwrap();
 
     let exactly_b = exactly_8_set
         .difference(&exactly_3_set)
         .copied()
-        .filter(|&c| if c == exactly_e { false } else { true })
-        .next()
+        .find(|&c| c != exactly_e)
         .unwrap();
 
     let exactly_d = exactly_4
         .chars()
-        .filter(|&c| match c {
+        .find(|&c| match c {
             _ if c == exactly_b => false,
             _ if c == exactly_c => false,
             _ if c == exactly_f => false,
             _ => true,
         })
-        .next()
         .unwrap();
 
     let exactly_g = exactly_3
         .chars()
-        .filter(|&c| match c {
+        .find(|&c| match c {
             _ if c == exactly_a => false,
             _ if c == exactly_c => false,
             _ if c == exactly_d => false,
             _ if c == exactly_f => false,
             _ => true,
         })
-        .next()
         .unwrap();
 
     Mapping::new(
@@ -262,7 +247,6 @@ fn derive_mapping(input: &Entry) -> Mapping {
     )
 }
 
-#[aoc(day8, part2)]
 fn part2(input: &[Entry]) -> u64 {
     input
         .iter()
@@ -279,12 +263,14 @@ fn part2(input: &[Entry]) -> u64 {
         .sum::<u64>()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
 
-    const INPUT_TEXT: &'static str =
-        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+const INPUT_TEXT: &str =
+    "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
     edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
     fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
     fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
@@ -295,15 +281,6 @@ mod tests {
     egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
     gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
 
-    #[test]
-    fn test_part1() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part1(&data), 26);
-    }
-
-    #[test]
-    fn test_part2() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part2(&data), 61229);
-    }
+crate::examples! {
+    INPUT_TEXT => part1: 26, part2: 61229;
 }
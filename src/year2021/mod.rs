@@ -0,0 +1,21 @@
+use crate::registry::Registry;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+pub fn register(registry: &mut Registry) {
+    day1::register(&mut registry.day(2021, 1));
+    day2::register(&mut registry.day(2021, 2));
+    day3::register(&mut registry.day(2021, 3));
+    day4::register(&mut registry.day(2021, 4));
+    day5::register(&mut registry.day(2021, 5));
+    day6::register(&mut registry.day(2021, 6));
+    day7::register(&mut registry.day(2021, 7));
+    day8::register(&mut registry.day(2021, 8));
+}
@@ -0,0 +1,192 @@
+use crate::error::{Error, Result};
+use crate::registry::{Answer, Day, Example, IntoAnswer};
+use crate::solution::{PartFn, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// The `k` largest totals, largest first.  Only `k` totals are held at any
+/// time, so the totals can come straight from [`totals`] without ever
+/// being collected.
+pub fn top_k<T: Ord>(totals: impl IntoIterator<Item = T>, k: usize) -> Vec<T> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for total in totals {
+        heap.push(Reverse(total));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec().into_iter().map(|x| x.0).collect()
+}
+
+/// The caloric total of each elf in an input read line by line, for inputs
+/// too big to load.  Nothing but the running total of the current elf is
+/// kept.
+pub fn totals(reader: impl BufRead) -> impl Iterator<Item = Result<u64>> {
+    Totals {
+        reader,
+        line: String::new(),
+        number: 0,
+        done: false,
+    }
+}
+
+struct Totals<R> {
+    reader: R,
+    line: String,
+    number: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Totals<R> {
+    // An error about `offending`, a slice of the current line.
+    fn error(&self, offending: &str, message: impl Into<String>) -> Error {
+        let line = self.line.trim_end_matches(['\r', '\n']);
+        let mut error = Error::at(line, offending, message);
+        if let Some(position) = &mut error.position {
+            position.line = self.number;
+        }
+        error
+    }
+}
+
+impl<R: BufRead> Iterator for Totals<R> {
+    type Item = Result<u64>;
+
+    fn next(&mut self) -> Option<Result<u64>> {
+        let mut total: Option<u64> = None;
+        while !self.done {
+            self.line.clear();
+            self.number += 1;
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(_) => (),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(self.error("", e.to_string())));
+                }
+            }
+
+            let line = self.line.trim();
+            if line.is_empty() {
+                if total.is_some() {
+                    break;
+                }
+                continue;
+            }
+            let message = match line.parse::<u64>() {
+                Ok(x) => match total.unwrap_or(0).checked_add(x) {
+                    Some(sum) => {
+                        total = Some(sum);
+                        continue;
+                    }
+                    None => "the calories of this elf add up past the largest u64",
+                },
+                Err(_) => "expected a number of calories",
+            };
+            self.done = true;
+            return Some(Err(self.error(line, message)));
+        }
+        total.map(Ok)
+    }
+}
+
+struct Calories;
+
+impl Calories {
+    fn part2(totals: &[u64]) -> Result<Answer> {
+        top_k(totals.iter().copied(), 3)
+            .iter()
+            .try_fold(0u64, |sum, &x| sum.checked_add(x))
+            .ok_or_else(|| Error::new("the top three totals add up past the largest u64"))?
+            .into_answer()
+    }
+}
+
+impl Solution for Calories {
+    // The total of each elf, the single items are never needed again.
+    type Parsed = Vec<u64>;
+
+    const PART2: Option<PartFn<Self>> = Some(|x| Self::part2(x));
+
+    fn parse(input: &str) -> Result<Vec<u64>> {
+        totals(input.as_bytes()).collect()
+    }
+
+    fn part1(totals: &Vec<u64>) -> Result<Answer> {
+        let top = top_k(totals.iter().copied(), 1);
+        let total = top
+            .first()
+            .ok_or_else(|| Error::new("no elves in the input"))?;
+        total.into_answer()
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT_TEXT).part1(24000).part2(45000)]
+    }
+}
+
+pub fn register(day: &mut Day) {
+    day.solution::<Calories>();
+}
+
+const INPUT_TEXT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+#[cfg(test)]
+mod example_tests {
+    #[test]
+    fn test_examples() {
+        crate::solution::check::<super::Calories>();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_top_k() {
+        let totals = [6000, 4000, 11000, 24000, 10000];
+        assert_eq!(top_k(totals, 3), [24000, 11000, 10000]);
+        assert_eq!(top_k(totals, 10), [24000, 11000, 10000, 6000, 4000]);
+        assert_eq!(top_k(totals, 0), []);
+        assert_eq!(top_k(["b", "c", "a"], 2), ["c", "b"]);
+    }
+
+    #[test]
+    fn test_streaming() {
+        let crlf = "\r\n\r\n1\r\n2\r\n\r\n\r\n3\r\n";
+        let streamed: Vec<u64> = totals(crlf.as_bytes()).collect::<Result<_>>().unwrap();
+        assert_eq!(streamed, [3, 3]);
+
+        let error = Calories::parse("1\n\n x\n").unwrap_err();
+        assert_eq!(error.message, "expected a number of calories");
+        let position = error.position.unwrap();
+        assert_eq!((position.line, position.column), (3, 2));
+        assert_eq!(position.text, " x");
+
+        let huge = format!("{}\r\n1\r\n", u64::MAX);
+        let error = totals(huge.as_bytes())
+            .collect::<Result<Vec<u64>>>()
+            .unwrap_err();
+        assert_eq!(
+            error.message,
+            "the calories of this elf add up past the largest u64"
+        );
+        assert_eq!(error.position.unwrap().line, 2);
+    }
+}
@@ -1,41 +1,37 @@
-use aoc_runner_derive::{aoc, aoc_generator};
+use crate::bitmap::Bitmap;
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::registry::Day;
 
 struct VirtualMachine<'a> {
     x: i32,
     cycle: u32,
 
     interrupts: &'a [u32],
+    // The instructions still to run, the next one last.
     instructions: Vec<Instruction>,
-
-    // Number of cycles before applying the next instruction
-    next_cycles: u32,
-
-    // Next instrution to apply.
-    next: Instruction,
 }
 
 impl<'a> VirtualMachine<'a> {
-    fn new(interrupts: &'a [u32], mut instructions: Vec<Instruction>) -> Self {
+    fn new(interrupts: &'a [u32], mut instructions: Vec<Instruction>) -> Result<Self> {
+        if instructions.is_empty() {
+            return Err(Error::new("there are no instructions to run"));
+        }
         instructions.reverse();
-        let next = instructions.pop().unwrap();
-        Self {
+        Ok(Self {
             x: 1,
             cycle: 0,
             interrupts,
             instructions,
-
-            next_cycles: next.cycles(),
-            next,
-        }
+        })
     }
 
     fn tick_part1(&mut self) -> Option<Option<i32>> {
-        let instruction = match self.instructions.pop() {
-            Some(x) => x,
-            None => return None,
-        };
+        let instruction = self.instructions.pop()?;
 
-        self.cycle += instruction.cycles();
+        // `cycles` counts the extra cycles an instruction spends before it
+        // applies, the cycle it applies on is on top of that.
+        self.cycle += instruction.cycles() + 1;
         let res = if let Some(x) = self.interrupts.first() {
             if self.cycle >= *x {
                 self.interrupts = &self.interrupts[1..];
@@ -67,6 +63,14 @@ impl<'a> VirtualMachine<'a> {
         ];
         let mut cursor: usize = 0;
 
+        // Next instruction to apply, and the number of cycles before it
+        // applies.
+        let mut next = match self.instructions.pop() {
+            Some(x) => x,
+            None => return display,
+        };
+        let mut next_cycles = next.cycles();
+
         'instruction: loop {
             let cursor_in_range = (self.x - 1..=self.x + 1)
                 .into_iter()
@@ -77,8 +81,8 @@ impl<'a> VirtualMachine<'a> {
             cursor += 1;
             self.cycle += 1;
 
-            if self.next_cycles == 0 {
-                match self.next {
+            if next_cycles == 0 {
+                match next {
                     Instruction::Addx(next_x) => self.x += next_x,
                     Instruction::Noop => {
                         // nothing to do here.
@@ -89,18 +93,18 @@ impl<'a> VirtualMachine<'a> {
                 }
                 match self.instructions.pop() {
                     Some(instruction) => {
-                        self.next_cycles = instruction.cycles();
-                        self.next = instruction;
+                        next_cycles = instruction.cycles();
+                        next = instruction;
                     }
                     // No more instructions, bail.
                     None => break 'instruction,
                 }
             } else {
-                self.next_cycles -= 1;
+                next_cycles -= 1;
             }
         }
 
-        return display;
+        display
     }
 }
 
@@ -119,27 +123,24 @@ impl Instruction {
     }
 }
 
-#[aoc_generator(day10)]
-fn generator(input: &str) -> Vec<Instruction> {
-    input
+fn generator(input: &str) -> Result<Vec<Instruction>> {
+    Text::new(input)
         .lines()
         .map(|line| {
             let line = line.trim();
-            if line == "noop" {
-                return Instruction::Noop;
+            if line.as_str() == "noop" {
+                return Ok(Instruction::Noop);
             }
-            let val = line.rsplit_once(" ").unwrap().1.parse().unwrap();
-            return Instruction::Addx(val);
+            let [val] = line.scan("addx {}")?;
+            Ok(Instruction::Addx(val.number()?))
         })
         .collect()
 }
 
-// part 1 is super broken
-#[aoc(day10, part1)]
-fn part1(input: &[Instruction]) -> i32 {
+fn part1(input: &[Instruction]) -> Result<i32> {
     let interrupts = vec![20, 60, 100, 140, 180, 220];
     let mut signals = Vec::with_capacity(interrupts.len());
-    let mut machine = VirtualMachine::new(&interrupts, input.to_vec());
+    let mut machine = VirtualMachine::new(&interrupts, input.to_vec())?;
 
     while let Some(x) = machine.tick_part1() {
         if let Some(y) = x {
@@ -147,37 +148,25 @@ fn part1(input: &[Instruction]) -> i32 {
         }
     }
 
-    return signals
+    Ok(signals
         .into_iter()
-        .zip(interrupts.into_iter())
+        .zip(interrupts)
         .map(|(x, y)| x * (y as i32))
-        .sum();
+        .sum())
 }
 
-#[aoc(day10, part2)]
-fn part2(input: &[Instruction]) -> String {
-    let mut machine = VirtualMachine::new(&[], input.to_vec());
-
-    let display = machine.generate_display();
-    let result = display
-        .into_iter()
-        .map(|row| {
-            row.into_iter()
-                .map(|x| if x { '🦾' } else { '🍙' })
-                .collect::<String>()
-        })
-        .collect::<Vec<String>>()
-        .join("\n");
-
-    println!("{}", result);
-    return result;
+fn part2(input: &[Instruction]) -> Result<Bitmap> {
+    let mut machine = VirtualMachine::new(&[], input.to_vec())?;
+    Ok(Bitmap::from_rows(machine.generate_display()))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
 
-    const INPUT_TEXT: &str = "addx 15
+const INPUT_TEXT: &str = "addx 15
 addx -11
 addx 6
 addx -3
@@ -325,15 +314,45 @@ noop
 noop
 ";
 
+// The example does not draw letters, so the answer is the picture itself.
+crate::examples! {
+    INPUT_TEXT => part1: 13140, part2: "██░░██░░██░░██░░██░░██░░██░░██░░██░░██░░
+███░░░███░░░███░░░███░░░███░░░███░░░███░
+████░░░░████░░░░████░░░░████░░░░████░░░░
+█████░░░░░█████░░░░░█████░░░░░█████░░░░░
+██████░░░░░░██████░░░░░░██████░░░░░░████
+███████░░░░░░░███████░░░░░░░███████░░░░░";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input::{default_root, Inputs};
+
     #[test]
-    fn test_part1() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part1(&data), 13140);
+    fn test_display() {
+        let display = VirtualMachine::new(&[], generator(INPUT_TEXT).unwrap())
+            .unwrap()
+            .generate_display();
+        let bitmap = Bitmap::from_rows(display);
+        assert!(bitmap.get(0, 0) && bitmap.get(1, 0) && !bitmap.get(2, 0));
+        assert_eq!(bitmap.ocr(), None);
+
+        let input = Inputs::new(default_root()).read(2022, 10).unwrap();
+        let display = VirtualMachine::new(&[], generator(&input).unwrap())
+            .unwrap()
+            .generate_display();
+        assert_eq!(
+            Bitmap::from_rows(display).ocr().as_deref(),
+            Some("RKPJBPLA")
+        );
     }
 
     #[test]
-    fn test_part2() {
-        let data = generator(INPUT_TEXT);
-        assert_eq!(part2(&data), "🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙🦾🦾🍙🍙\n🦾🦾🦾🍙🍙🍙🦾🦾🦾🍙🍙🍙🦾🦾🦾🍙🍙🍙🦾🦾🦾🍙🍙🍙🦾🦾🦾🍙🍙🍙🦾🦾🦾🍙🍙🍙🦾🦾🦾🍙\n🦾🦾🦾🦾🍙🍙🍙🍙🦾🦾🦾🦾🍙🍙🍙🍙🦾🦾🦾🦾🍙🍙🍙🍙🦾🦾🦾🦾🍙🍙🍙🍙🦾🦾🦾🦾🍙🍙🍙🍙\n🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙\n🦾🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🍙🦾🦾🦾🦾\n🦾🦾🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙🍙🍙🦾🦾🦾🦾🦾🦾🦾🍙🍙🍙🍙🍙")
+    fn test_invalid() {
+        let error = generator("noop\naddx").unwrap_err();
+        assert_eq!(error.position.unwrap().line, 2);
+        assert!(generator("addx x").is_err());
+        assert!(part1(&generator("").unwrap()).is_err());
     }
 }
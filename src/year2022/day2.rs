@@ -0,0 +1,326 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::registry::Day;
+
+/// Every shape any variant of the game is played with.  They are listed so
+/// that each one beats the shapes an odd number of places before it around
+/// the circle, which is the rule for all of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+    Spock,
+    Lizard,
+}
+
+impl Shape {
+    const ALL: [Shape; 5] = [
+        Self::Rock,
+        Self::Paper,
+        Self::Scissors,
+        Self::Spock,
+        Self::Lizard,
+    ];
+
+    /// What playing this shape is worth on its own.
+    pub fn score(self) -> u32 {
+        self as u32 + 1
+    }
+
+    /// How a round goes for this shape played against `other`.
+    pub fn against(self, other: Shape) -> Outcome {
+        let n = Self::ALL.len();
+        match (self as usize + n - other as usize) % n {
+            0 => Outcome::Draw,
+            x if x % 2 == 1 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        self as u32 * 3
+    }
+}
+
+/// A variant of the game: which shapes are played and the letters the
+/// strategy guide uses for them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Game {
+    shapes: &'static [Shape],
+
+    // The letters for `shapes` in the opponent's column and in ours.
+    theirs: &'static str,
+    ours: &'static str,
+}
+
+impl Game {
+    pub const CLASSIC: Game = Game {
+        shapes: &[Shape::Rock, Shape::Paper, Shape::Scissors],
+        theirs: "ABC",
+        ours: "XYZ",
+    };
+
+    /// Rock paper scissors lizard Spock.  The guide letters for the two new
+    /// shapes are our own, the puzzle never uses them.
+    pub const LIZARD_SPOCK: Game = Game {
+        shapes: &Shape::ALL,
+        theirs: "ABCDE",
+        ours: "XYZUV",
+    };
+
+    /// A variant played with `shapes`, written as the letters of `theirs`
+    /// in the opponent's column and of `ours` in ours.  Every shape needs a
+    /// letter of its own in both, and against every shape there has to be
+    /// a way to lose, draw and win.
+    pub fn new(shapes: &'static [Shape], theirs: &'static str, ours: &'static str) -> Result<Self> {
+        for (column, letters) in [("their", theirs), ("our", ours)] {
+            let mut seen: Vec<char> = letters.chars().collect();
+            seen.sort_unstable();
+            seen.dedup();
+            if seen.len() != letters.chars().count() || seen.len() != shapes.len() {
+                return Err(Error::new(format!(
+                    "{} letters {:?} do not name the {} shapes one each",
+                    column,
+                    letters,
+                    shapes.len()
+                )));
+            }
+        }
+        for &opponent in shapes {
+            for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                if !shapes.iter().any(|x| x.against(opponent) == outcome) {
+                    return Err(Error::new(format!(
+                        "nothing to play against {:?} to {:?}",
+                        opponent, outcome
+                    )));
+                }
+            }
+        }
+        Ok(Self {
+            shapes,
+            theirs,
+            ours,
+        })
+    }
+
+    pub fn shapes(&self) -> &'static [Shape] {
+        self.shapes
+    }
+
+    fn shape(&self, letters: &str, letter: char) -> Option<Shape> {
+        letters
+            .chars()
+            .position(|x| x == letter)
+            .map(|idx| self.shapes[idx])
+    }
+
+    /// The shape to play against `opponent` for `outcome`.  When several
+    /// shapes would do, the one worth least is picked.  There always is one
+    /// against a shape of this variant, `None` can only come back for a
+    /// shape from outside it.
+    pub fn choose(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .copied()
+            .filter(|x| x.against(opponent) == outcome)
+            .min_by_key(|x| x.score())
+    }
+}
+
+/// What the second column of the strategy guide tells us to do.
+pub trait Meaning {
+    /// The shape to play against `opponent` when the guide says `letter`,
+    /// `None` if the letter means nothing.
+    fn choose(&self, game: &Game, opponent: Shape, letter: char) -> Option<Shape>;
+}
+
+/// The second column is the shape to play.
+pub struct PlayShape;
+
+impl Meaning for PlayShape {
+    fn choose(&self, game: &Game, _opponent: Shape, letter: char) -> Option<Shape> {
+        game.shape(game.ours, letter)
+    }
+}
+
+/// The second column is how the round has to end, `X` to lose, `Y` to draw
+/// and `Z` to win.
+pub struct AimFor;
+
+impl Meaning for AimFor {
+    fn choose(&self, game: &Game, opponent: Shape, letter: char) -> Option<Shape> {
+        let outcome = match letter {
+            'X' => Outcome::Lose,
+            'Y' => Outcome::Draw,
+            'Z' => Outcome::Win,
+            _ => return None,
+        };
+        game.choose(opponent, outcome)
+    }
+}
+
+/// One round as we will play it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub me: Shape,
+}
+
+impl Round {
+    pub fn score(&self) -> u32 {
+        self.me.score() + self.me.against(self.opponent).score()
+    }
+}
+
+/// Reads a strategy guide, working out each round with `meaning`.  Letters
+/// the game has no use for are errors.
+pub fn parse_guide(game: &Game, meaning: &impl Meaning, input: &str) -> Result<Vec<Round>> {
+    let letter = |text: Text| {
+        let mut chars = text.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(text.error("expected a single letter")),
+        }
+    };
+    Text::new(input)
+        .lines()
+        .map(|line| {
+            let [theirs, ours] = line.scan("{} {}")?;
+            let opponent = game
+                .shape(game.theirs, letter(theirs)?)
+                .ok_or_else(|| theirs.error(format!("expected one of {}", game.theirs)))?;
+            let me = meaning
+                .choose(game, opponent, letter(ours)?)
+                .ok_or_else(|| ours.error("not a letter this strategy knows"))?;
+            Ok(Round { opponent, me })
+        })
+        .collect()
+}
+
+fn score(rounds: &[Round]) -> u32 {
+    rounds.iter().map(Round::score).sum()
+}
+
+fn shapes(input: &str) -> Result<Vec<Round>> {
+    parse_guide(&Game::CLASSIC, &PlayShape, input)
+}
+
+fn outcomes(input: &str) -> Result<Vec<Round>> {
+    parse_guide(&Game::CLASSIC, &AimFor, input)
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(shapes, score)
+        .part2(outcomes, score)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "A Y
+B X
+C Z";
+
+crate::examples! {
+    INPUT_TEXT => part1: 15, part2: 12;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The score of every possible line, as the two parts used to spell out.
+    #[rustfmt::skip]
+    const PART1: [(&str, u32); 9] = [
+        ("A X", 4), ("A Y", 8), ("A Z", 3),
+        ("B X", 1), ("B Y", 5), ("B Z", 9),
+        ("C X", 7), ("C Y", 2), ("C Z", 6),
+    ];
+
+    #[rustfmt::skip]
+    const PART2: [(&str, u32); 9] = [
+        ("A X", 3), ("A Y", 4), ("A Z", 8),
+        ("B X", 1), ("B Y", 5), ("B Z", 9),
+        ("C X", 2), ("C Y", 6), ("C Z", 7),
+    ];
+
+    #[test]
+    fn test_tables() {
+        for (line, expected) in PART1 {
+            assert_eq!(score(&shapes(line).unwrap()), expected, "{}", line);
+        }
+        for (line, expected) in PART2 {
+            assert_eq!(score(&outcomes(line).unwrap()), expected, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        use Shape::*;
+        for (winner, loser) in [
+            (Scissors, Paper),
+            (Paper, Rock),
+            (Rock, Lizard),
+            (Lizard, Spock),
+            (Spock, Scissors),
+            (Scissors, Lizard),
+            (Lizard, Paper),
+            (Paper, Spock),
+            (Spock, Rock),
+            (Rock, Scissors),
+        ] {
+            assert_eq!(winner.against(loser), Outcome::Win);
+            assert_eq!(loser.against(winner), Outcome::Lose);
+        }
+        for shape in Shape::ALL {
+            assert_eq!(shape.against(shape), Outcome::Draw);
+            let wins = Shape::ALL
+                .iter()
+                .filter(|&&x| shape.against(x) == Outcome::Win)
+                .count();
+            assert_eq!(wins, 2);
+        }
+
+        let game = Game::LIZARD_SPOCK;
+        let rounds = parse_guide(&game, &PlayShape, "E U\nD Y").unwrap();
+        assert_eq!(score(&rounds), (4 + 6) + 2);
+        let rounds = parse_guide(&game, &AimFor, "D Z").unwrap();
+        assert_eq!(rounds[0].me, Paper);
+    }
+
+    #[test]
+    fn test_games() {
+        use Shape::*;
+        for game in [Game::CLASSIC, Game::LIZARD_SPOCK] {
+            assert_eq!(Game::new(game.shapes, game.theirs, game.ours), Ok(game));
+        }
+        assert!(Game::new(&[Rock, Paper, Scissors], "ABCD", "XYZ").is_err());
+        assert!(Game::new(&[Rock, Paper, Scissors], "ABB", "XYZ").is_err());
+        assert!(Game::new(&[Rock, Paper], "AB", "XY").is_err());
+
+        // Letters are counted in characters, not bytes.
+        let game = Game::new(&[Rock, Paper, Scissors], "ÀBC", "XYZ").unwrap();
+        assert_eq!(score(&parse_guide(&game, &PlayShape, "C X").unwrap()), 7);
+        assert_eq!(Game::CLASSIC.choose(Spock, Outcome::Draw), None);
+    }
+
+    #[test]
+    fn test_unknown_letters() {
+        let error = shapes("A Y\nD X").unwrap_err();
+        let position = error.position.unwrap();
+        assert_eq!((position.line, position.column), (2, 1));
+        assert_eq!(error.message, "expected one of ABC");
+
+        let error = outcomes("A U").unwrap_err();
+        assert_eq!(error.position.unwrap().column, 3);
+        assert!(shapes("A YY").is_err());
+    }
+}
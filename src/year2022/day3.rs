@@ -0,0 +1,227 @@
+use crate::error::{Error, Result};
+use crate::parse::Text;
+use crate::registry::Day;
+
+fn priority(item: char) -> u32 {
+    if item.is_ascii_uppercase() {
+        27 + (item as u32) - ('A' as u32)
+    } else {
+        1 + (item as u32) - ('a' as u32)
+    }
+}
+
+fn item(priority: u32) -> char {
+    if priority > 26 {
+        (b'A' + (priority - 27) as u8) as char
+    } else {
+        (b'a' + (priority - 1) as u8) as char
+    }
+}
+
+/// A set of items, with bit `priority - 1` set for each item in it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    /// Every one of the 52 items.
+    pub const ALL: Items = Items((1 << 52) - 1);
+
+    /// The items in a line, an error if it holds anything but letters.
+    pub fn parse(text: Text) -> Result<Items> {
+        if let Some(c) = text.as_str().chars().find(|c| !c.is_ascii_alphabetic()) {
+            return Err(text.error(format!("{:?} is not an item", c)));
+        }
+        Ok(Self::of(text.as_str()))
+    }
+
+    // `items` must be letters only.
+    fn of(items: &str) -> Items {
+        Items(items.chars().fold(0, |set, c| set | 1 << (priority(c) - 1)))
+    }
+
+    pub fn common(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the items, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |x| self.0 & 1 << (x - 1) != 0)
+    }
+
+    /// The priority of the only item in the set, an error saying what is
+    /// there instead otherwise.
+    pub fn only(self) -> Result<u32> {
+        match self.len() {
+            0 => Err(Error::new("no item")),
+            1 => Ok(self.0.trailing_zeros() + 1),
+            n => {
+                let items: Vec<String> = self.priorities().map(|x| item(x).to_string()).collect();
+                Err(Error::new(format!("{} items ({})", n, items.join(", "))))
+            }
+        }
+    }
+}
+
+pub struct Rucksack {
+    left: Items,
+    right: Items,
+}
+
+impl Rucksack {
+    fn parse(line: Text) -> Result<Self> {
+        Items::parse(line)?;
+        let items = line.as_str();
+        if items.len() % 2 == 1 {
+            return Err(line.error(format!(
+                "{} items do not split into two equal compartments",
+                items.len()
+            )));
+        }
+        let (left, right) = items.split_at(items.len() / 2);
+        Ok(Self {
+            left: Items::of(left),
+            right: Items::of(right),
+        })
+    }
+}
+
+/// The priority of the badge of a group, the one item every elf in it
+/// carries.  Groups can be any size.
+pub fn badge(group: &[Items]) -> Result<u32> {
+    group
+        .iter()
+        .fold(Items::ALL, |all, &x| all.common(x))
+        .only()
+}
+
+/// Splits the rucksacks into groups of `size` elves.
+pub fn groups(input: &str, size: usize) -> Result<Vec<Vec<Items>>> {
+    if size == 0 {
+        return Err(Error::new("groups must have at least one elf"));
+    }
+    let rucksacks = Text::new(input)
+        .lines()
+        .map(Items::parse)
+        .collect::<Result<Vec<_>>>()?;
+    if rucksacks.len() % size != 0 {
+        return Err(Error::new(format!(
+            "{} rucksacks do not split into groups of {}",
+            rucksacks.len(),
+            size
+        )));
+    }
+    Ok(rucksacks.chunks(size).map(|x| x.to_vec()).collect())
+}
+
+fn generator_part1(input: &str) -> Result<Vec<Rucksack>> {
+    Text::new(input).lines().map(Rucksack::parse).collect()
+}
+
+fn generator_part2(input: &str) -> Result<Vec<Vec<Items>>> {
+    groups(input, 3)
+}
+
+fn part1(input: &[Rucksack]) -> Result<u32> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(idx, rucksack)| {
+            rucksack.left.common(rucksack.right).only().map_err(|e| {
+                Error::new(format!(
+                    "rucksack {}: compartments share {}",
+                    idx + 1,
+                    e.message
+                ))
+            })
+        })
+        .sum()
+}
+
+fn part2(input: &[Vec<Items>]) -> Result<u32> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(idx, group)| {
+            badge(group)
+                .map_err(|e| Error::new(format!("group {}: elves share {}", idx + 1, e.message)))
+        })
+        .sum()
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator_part1, part1)
+        .part2(generator_part2, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+crate::examples! {
+    INPUT_TEXT => part1: 157, part2: 70;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        let items = Items::of("aAzZa");
+        assert_eq!(items.len(), 4);
+        assert_eq!(items.priorities().collect::<Vec<_>>(), [1, 26, 27, 52]);
+        assert_eq!(items.common(Items::of("Zb")).only(), Ok(52));
+        assert_eq!(items.only().unwrap_err().message, "4 items (a, z, A, Z)");
+        assert_eq!(Items::default().only().unwrap_err().message, "no item");
+        assert_eq!((1..=52).map(item).map(priority).sum::<u32>(), 52 * 53 / 2);
+    }
+
+    #[test]
+    fn test_groups() {
+        let pairs = groups("abc\ncde\nxyz\nxYz\n", 2).unwrap();
+        assert_eq!(badge(&pairs[0]), Ok(3));
+        assert_eq!(badge(&pairs[1]).unwrap_err().message, "2 items (x, z)");
+
+        let all = groups(INPUT_TEXT, 6).unwrap();
+        assert_eq!(badge(&all[0]).unwrap_err().message, "no item");
+        assert!(groups(INPUT_TEXT, 0).is_err());
+
+        let error = groups(INPUT_TEXT, 4).unwrap_err();
+        assert_eq!(error.message, "6 rucksacks do not split into groups of 4");
+    }
+
+    #[test]
+    fn test_invalid() {
+        let error = generator_part1("abcb\nabc").err().unwrap();
+        assert_eq!(error.position.unwrap().line, 2);
+        assert_eq!(
+            error.message,
+            "3 items do not split into two equal compartments"
+        );
+
+        let error = generator_part1("ab1b").err().unwrap();
+        assert_eq!(error.message, "'1' is not an item");
+
+        let rucksacks = generator_part1("abcd\nabab").unwrap();
+        let error = part1(&rucksacks).unwrap_err();
+        assert_eq!(error.message, "rucksack 1: compartments share no item");
+        let rucksacks = generator_part1("abab").unwrap();
+        assert_eq!(
+            part1(&rucksacks).unwrap_err().message,
+            "rucksack 1: compartments share 2 items (a, b)"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::error::Result;
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::Text;
+use crate::registry::Day;
+
+// The sections an elf is assigned to clean.  Sections are `u32`s, the ends
+// are kept wider so that a range can run up to the last section.
+type Assignment = Interval<u64>;
+
+/// Makes an assignment out of the sections at either end.
+fn parse_assignment(first: Text, last: Text) -> Result<Assignment> {
+    let (start, end) = (first.number::<u32>()?, last.number::<u32>()?);
+    if start > end {
+        return Err(first.error("range ends before it starts"));
+    }
+    Assignment::inclusive(start.into(), end.into()).map_err(|e| last.error(e.message))
+}
+
+fn generator(input: &str) -> Result<Vec<(Assignment, Assignment)>> {
+    Text::new(input)
+        .lines()
+        .map(|line| {
+            let [a, b, c, d] = line.scan("{}-{},{}-{}")?;
+            Ok((parse_assignment(a, b)?, parse_assignment(c, d)?))
+        })
+        .collect()
+}
+
+/// Every elf of the pairs in input order, so elf `2 * k` and `2 * k + 1`
+/// are pair `k`.
+pub fn elves(pairs: &[(Assignment, Assignment)]) -> impl Iterator<Item = Assignment> + '_ {
+    pairs.iter().flat_map(|&(l, r)| [l, r])
+}
+
+/// The sections at least one elf is assigned to.
+pub fn coverage(pairs: &[(Assignment, Assignment)]) -> IntervalSet<u64> {
+    elves(pairs).collect()
+}
+
+/// The sections more than `n` elves are assigned to.
+pub fn covered_by_more_than(pairs: &[(Assignment, Assignment)], n: usize) -> IntervalSet<u64> {
+    // Sweeps the section numbers, counting elves as their ranges start
+    // and end.
+    let mut events: Vec<(u64, isize)> = elves(pairs)
+        .filter(|x| !x.is_empty())
+        .flat_map(|x| [(x.start, 1), (x.end, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut covered = IntervalSet::new();
+    let mut count = 0;
+    for (idx, &(section, change)) in events.iter().enumerate() {
+        count += change;
+        if let Some(&(next, _)) = events.get(idx + 1) {
+            if count > n as isize && next > section {
+                covered.insert(Interval::new(section, next));
+            }
+        }
+    }
+    covered
+}
+
+/// How many sections the two elves of each pair share, in input order.
+pub fn overlap_widths(pairs: &[(Assignment, Assignment)]) -> Vec<u64> {
+    pairs
+        .iter()
+        .map(|(l, r)| l.intersection(r).map_or(0, |x| x.len()))
+        .collect()
+}
+
+/// The elves, numbered as by [`elves`], whose every section is also
+/// assigned to some other elf.  Each is redundant on its own, two elves
+/// with the same range are both listed though only one can go.
+pub fn redundant(pairs: &[(Assignment, Assignment)]) -> Vec<usize> {
+    let shared = covered_by_more_than(pairs, 1);
+    elves(pairs)
+        .enumerate()
+        .filter(|(_, x)| shared.contains_interval(x))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn part1(input: &[(Assignment, Assignment)]) -> u32 {
+    input
+        .iter()
+        .filter(|&(l, r)| l.contains_interval(r) || r.contains_interval(l))
+        .count()
+        .try_into()
+        .unwrap()
+}
+
+fn part2(input: &[(Assignment, Assignment)]) -> u32 {
+    input
+        .iter()
+        .filter(|&(l, r)| l.overlaps(r))
+        .count()
+        .try_into()
+        .unwrap()
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+crate::examples! {
+    INPUT_TEXT => part1: 2, part2: 4;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_analytics() {
+        let pairs = generator(INPUT_TEXT).unwrap();
+        assert_eq!(coverage(&pairs).intervals(), [Assignment::new(2, 10)]);
+        assert_eq!(overlap_widths(&pairs), [0, 0, 1, 5, 1, 3]);
+
+        // Counted section by section, the way the sweep should.
+        for n in 0..8 {
+            let expected: IntervalSet<u64> = (0..12)
+                .filter(|&x| elves(&pairs).filter(|a| a.contains(x)).count() > n)
+                .map(|x| Assignment::new(x, x + 1))
+                .collect();
+            assert_eq!(covered_by_more_than(&pairs, n), expected, "{}", n);
+        }
+        assert_eq!(covered_by_more_than(&pairs, 1).len(), 7);
+
+        // Only 7-9 has a section to itself.
+        let expected: Vec<usize> = (0..12).filter(|&x| x != 5).collect();
+        assert_eq!(redundant(&pairs), expected);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let pairs = generator("1-4294967295,3-4").unwrap();
+        assert_eq!(part1(&pairs), 1);
+        assert_eq!(overlap_widths(&pairs), [2]);
+
+        let error = generator("5-3,1-2").unwrap_err();
+        assert_eq!(error.message, "range ends before it starts");
+        assert_eq!(error.position.unwrap().column, 1);
+
+        let pairs = generator("2-4,6-8\r\n2-8,3-7\r\n").unwrap();
+        assert_eq!(part1(&pairs), 1);
+    }
+}
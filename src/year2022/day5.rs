@@ -0,0 +1,359 @@
+use crate::error::{Error, Result};
+use crate::interval::Interval;
+use crate::parse::Text;
+use crate::registry::Day;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Day5 {
+    pub state: Stacks,
+    pub operations: Vec<Operation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation {
+    pub from: usize,
+    pub to: usize,
+    pub count: usize,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+/// How a crane moves several crates at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crane {
+    /// One crate at a time, so they land in reverse order.
+    CrateMover9000,
+    /// All of them at once, keeping their order.
+    CrateMover9001,
+}
+
+/// The stacks of crates, bottom crate first.  Displays as the puzzle's
+/// diagram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stacks(Vec<Vec<String>>);
+
+impl Stacks {
+    /// The crates of each stack, bottom crate first.
+    pub fn stacks(&self) -> &[Vec<String>] {
+        &self.0
+    }
+
+    /// Carries out one move, an error if it names a stack that does not
+    /// exist or the stack it takes from runs out.
+    pub fn apply(&mut self, operation: &Operation, crane: Crane) -> Result<()> {
+        let count = self.0.len();
+        for stack in [operation.from, operation.to] {
+            if !(1..=count).contains(&stack) {
+                return Err(Error::new(format!(
+                    "cannot {}, there are only stacks 1 to {}",
+                    operation, count
+                )));
+            }
+        }
+        let from = &mut self.0[operation.from - 1];
+        if from.len() < operation.count {
+            return Err(Error::new(format!(
+                "cannot {}, stack {} only has {} crates",
+                operation,
+                operation.from,
+                from.len()
+            )));
+        }
+        let mut moved = from.split_off(from.len() - operation.count);
+        if crane == Crane::CrateMover9000 {
+            moved.reverse();
+        }
+        self.0[operation.to - 1].append(&mut moved);
+        Ok(())
+    }
+
+    /// The crate on top of each stack, all run together.
+    pub fn tops(&self) -> Result<String> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, stack)| {
+                stack
+                    .last()
+                    .map(String::as_str)
+                    .ok_or_else(|| Error::new(format!("stack {} is empty", idx + 1)))
+            })
+            .collect()
+    }
+}
+
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Every column is as wide as the widest crate or stack number, in
+        // characters since that is what `{:^width$}` pads by.
+        let crates = self.0.iter().flatten().map(|x| x.chars().count() + 2);
+        let width = crates
+            .chain([self.0.len().to_string().len()])
+            .max()
+            .unwrap();
+        let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut rows = Vec::new();
+        for level in (0..height).rev() {
+            let cells: Vec<String> = self
+                .0
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(x) => format!("{:^width$}", format!("[{}]", x)),
+                    None => " ".repeat(width),
+                })
+                .collect();
+            rows.push(cells.join(" "));
+        }
+        let labels: Vec<String> = (1..=self.0.len())
+            .map(|x| format!("{:^width$}", x))
+            .collect();
+        rows.push(labels.join(" "));
+
+        let rows: Vec<&str> = rows.iter().map(|x| x.trim_end()).collect();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+impl Day5 {
+    /// Runs every move with `crane`, handing each move and the stacks after
+    /// it to `visit`.  Returns the stacks at the end.
+    pub fn replay(
+        &self,
+        crane: Crane,
+        mut visit: impl FnMut(&Operation, &Stacks),
+    ) -> Result<Stacks> {
+        let mut state = self.state.clone();
+        for operation in self.operations.iter() {
+            state.apply(operation, crane)?;
+            visit(operation, &state);
+        }
+        Ok(state)
+    }
+}
+
+// The runs of non-blank characters in a row, with the characters each
+// covers.  Spans count characters rather than bytes, so that a crate with a
+// non-ASCII name lines up with the stack numbers the way it is drawn.
+fn tokens(row: Text) -> Result<Vec<(Interval<usize>, Text)>> {
+    let text = row.as_str().trim_end_matches('\r');
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let chars = text.char_indices().chain([(text.len(), ' ')]).enumerate();
+    for (column, (idx, c)) in chars {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some((column, idx)),
+            (Some((first_column, first_idx)), true) => {
+                let span = Interval::new(first_column, column);
+                tokens.push((span, row.slice(first_idx..idx)?));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses the crate diagram: crates like `[Z]` stacked above the stack
+/// numbers.  A crate belongs to the stack whose number is written under
+/// it, so stacks can be numbered past 9 and crates can have longer names
+/// as long as everything lines up.
+fn parse_state(state: Text) -> Result<Stacks> {
+    let rows: Vec<Text> = state.lines().collect();
+    let (&last, crates) = rows.split_last().expect("blocks are never empty");
+    let labels = tokens(last)?;
+    if labels.is_empty() {
+        return Err(last.error("expected a row of stack numbers"));
+    }
+    for (idx, (_, label)) in labels.iter().enumerate() {
+        if label.number::<usize>().ok() != Some(idx + 1) {
+            return Err(label.error(format!("expected stack number {}", idx + 1)));
+        }
+    }
+
+    // Read from the bottom up so the crates are pushed in stacking order.
+    let mut stacks = vec![Vec::new(); labels.len()];
+    for (level, row) in crates.iter().rev().enumerate() {
+        for (span, cell) in tokens(*row)? {
+            let [name] = cell.scan("[{}]")?;
+            if name.is_empty() {
+                return Err(cell.error("expected a crate name"));
+            }
+
+            let mut under = labels
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| x.0.overlaps(&span));
+            let stack = match (under.next(), under.next()) {
+                (Some((idx, _)), None) => &mut stacks[idx],
+                (None, _) => return Err(cell.error("expected a stack number under the crate")),
+                (Some(_), Some(_)) => {
+                    return Err(cell.error("crate is over more than one stack number"))
+                }
+            };
+            if stack.len() != level {
+                return Err(cell.error("crate has nothing under it"));
+            }
+            stack.push(name.as_str().to_owned());
+        }
+    }
+    Ok(Stacks(stacks))
+}
+
+/// Parses the `move` lines, checking that every stack they name exists.
+fn parse_operations(operations: Text, stacks: usize) -> Result<Vec<Operation>> {
+    operations
+        .lines()
+        .map(|line| {
+            let [count, from, to] = line.scan("move {} from {} to {}")?;
+            let stack = |x: Text| -> Result<usize> {
+                match x.number()? {
+                    n @ 1.. if n <= stacks => Ok(n),
+                    _ => Err(x.error(format!("there are only stacks 1 to {}", stacks))),
+                }
+            };
+            Ok(Operation {
+                count: count.number()?,
+                from: stack(from)?,
+                to: stack(to)?,
+            })
+        })
+        .collect()
+}
+
+pub fn generator(input: &str) -> Result<Day5> {
+    let mut blocks = Text::new(input).blocks();
+    let state = blocks
+        .next()
+        .ok_or_else(|| Error::at_end(input, "expected a crate diagram"))?;
+    let operations = blocks.next().ok_or_else(|| {
+        Error::at_end(
+            input,
+            "expected a blank line between the crates and the moves",
+        )
+    })?;
+    let state = parse_state(state)?;
+    let operations = parse_operations(operations, state.stacks().len())?;
+    Ok(Day5 { state, operations })
+}
+
+fn part1(input: &Day5) -> Result<String> {
+    input.replay(Crane::CrateMover9000, |_, _| ())?.tops()
+}
+
+fn part2(input: &Day5) -> Result<String> {
+    input.replay(Crane::CrateMover9001, |_, _| ())?.tops()
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+crate::examples! {
+    INPUT_TEXT => part1: "CMZ", part2: "MCD";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let day = generator(INPUT_TEXT).unwrap();
+        assert_eq!(
+            day.state.to_string(),
+            INPUT_TEXT.split("\n\n").next().unwrap()
+        );
+
+        let mut diagrams = Vec::new();
+        day.replay(Crane::CrateMover9000, |_, x| diagrams.push(x.to_string()))
+            .unwrap();
+        assert_eq!(diagrams[0], "[D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3");
+        assert_eq!(
+            diagrams[3],
+            "        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3"
+        );
+    }
+
+    #[test]
+    fn test_wide_diagram() {
+        let state = concat!(
+            "[AB]                                         [K]\n",
+            "[CD] [E]  [F]  [G]  [H]  [I]  [J]  [Q]  [R]  [S]\n",
+            " 1    2    3    4    5    6    7    8    9    10",
+        );
+        let input = format!("{}\n\nmove 2 from 1 to 10\n", state);
+        let day = generator(&input).unwrap();
+        assert_eq!(day.state.stacks()[0], ["CD", "AB"]);
+        assert_eq!(day.state.stacks()[9], ["S", "K"]);
+        assert_eq!(day.state.to_string(), state);
+
+        let state = day.replay(Crane::CrateMover9001, |_, _| ()).unwrap();
+        assert_eq!(state.stacks()[9], ["S", "K", "CD", "AB"]);
+        let error = state.tops().unwrap_err();
+        assert_eq!(error.message, "stack 1 is empty");
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        let state = concat!("[É] [B]\n", "[Ω] [D] [F]\n", " 1   2   3",);
+        let input = format!("{}\n\nmove 1 from 1 to 3\n", state);
+        let day = generator(&input).unwrap();
+        assert_eq!(day.state.stacks()[0], ["Ω", "É"]);
+        assert_eq!(day.state.stacks()[1], ["D", "B"]);
+        assert_eq!(day.state.to_string(), state);
+        assert_eq!(part1(&day).unwrap(), "ΩBÉ");
+
+        let state = concat!("[ÉÉ]\n", "[ΩΩ] [D]\n", " 1    2",);
+        let day = generator(&format!("{}\n\nmove 1 from 1 to 2\n", state)).unwrap();
+        assert_eq!(day.state.to_string(), state);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let error = generator("[ABC]\n 1 2\n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(error.message, "crate is over more than one stack number");
+
+        let error = generator("[A]\n    [B]\n 1   2\n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(error.message, "crate has nothing under it");
+
+        let error = generator("[A] [B]\n 1   3\n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!(error.message, "expected stack number 2");
+        assert_eq!(error.position.unwrap().column, 6);
+
+        let mut state = generator("[A]\n 1\n\nmove 1 from 1 to 1").unwrap().state;
+        for from in [0, 2] {
+            let operation = Operation {
+                from,
+                to: 1,
+                count: 1,
+            };
+            assert!(state.apply(&operation, Crane::CrateMover9000).is_err());
+        }
+
+        let day = generator("[A] [B]\n 1   2\n\nmove 2 from 1 to 2").unwrap();
+        let error = part1(&day).unwrap_err();
+        assert_eq!(
+            error.message,
+            "cannot move 2 from 1 to 2, stack 1 only has 1 crates"
+        );
+    }
+}
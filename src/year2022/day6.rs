@@ -0,0 +1,42 @@
+use crate::error::{Error, Result};
+use crate::registry::Day;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+fn part1(input: &str) -> Result<usize> {
+    for (idx, (a, b, c, d)) in input.chars().tuple_windows().enumerate() {
+        if a != b && a != c && a != d && b != c && b != d && c != d {
+            return Ok(idx + 4);
+        }
+    }
+    Err(Error::new("no start-of-packet marker in the datastream"))
+}
+
+fn part2(input: &str) -> Result<usize> {
+    let chars: Vec<char> = input.chars().collect();
+    for (idx, window) in chars.windows(14).enumerate() {
+        let mut set = HashSet::with_capacity(14);
+        for c in window {
+            set.insert(*c);
+        }
+        if set.len() == 14 {
+            return Ok(idx + 14);
+        }
+    }
+
+    Err(Error::new("no start-of-message marker in the datastream"))
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(str::to_owned, part1)
+        .part2(str::to_owned, part2)
+        .examples(examples());
+}
+
+crate::examples! {
+    "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => part1: 7, part2: 19;
+    "bvwbjplbgvbhsrlpgdmjqwftvncz" => part1: 5, part2: 23;
+    "nppdvjthqldpwncqszvftbrmjlhg" => part1: 6, part2: 23;
+    "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => part1: 10, part2: 29;
+    "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => part1: 11, part2: 26;
+}
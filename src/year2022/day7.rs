@@ -0,0 +1,213 @@
+use crate::error::Result;
+use crate::parse::Text;
+use crate::registry::Day;
+
+type Inode = usize;
+
+struct FileSystem {
+    files: Vec<File>,
+}
+
+impl FileSystem {
+    fn new() -> Self {
+        let root = File {
+            name: "/".to_string(),
+            typ: FileType::Folder(vec![]),
+        };
+        Self { files: vec![root] }
+    }
+
+    fn root_inode(&self) -> Inode {
+        0 as Inode
+    }
+
+    fn add_file(&mut self, parent: Inode, name: &str, size: usize) -> Inode {
+        let child = File {
+            name: name.to_string(),
+            typ: FileType::File(size),
+        };
+        self.add(parent, child)
+    }
+
+    fn add_folder(&mut self, parent: Inode, name: &str) -> Inode {
+        let child = File {
+            name: name.to_string(),
+            typ: FileType::Folder(vec![]),
+        };
+        self.add(parent, child)
+    }
+
+    fn add(&mut self, parent: Inode, child: File) -> Inode {
+        self.files.push(child);
+        let child_inode = self.files.len() - 1 as Inode;
+
+        let p = self.files.get_mut(parent).unwrap();
+        match p.typ {
+            FileType::File(_) => panic!("Attempted to add a child to a regular file"),
+            FileType::Folder(ref mut children) => children.push(child_inode),
+        }
+
+        child_inode
+    }
+
+    fn get(&self, that: Inode) -> &File {
+        self.files.get(that).unwrap()
+    }
+}
+
+#[derive(Debug)]
+enum FileType {
+    // file size
+    File(usize),
+
+    // indicies into files vector representing direct descendents
+    Folder(Vec<Inode>),
+}
+
+#[derive(Debug)]
+struct File {
+    name: String,
+    typ: FileType,
+}
+
+impl File {
+    fn get_child_inode(&self, fs: &FileSystem, name: &str) -> Option<Inode> {
+        if let FileType::Folder(ref children) = self.typ {
+            children
+                .iter()
+                .copied()
+                .find(|&child_inode| fs.get(child_inode).name == name)
+        } else {
+            None
+        }
+    }
+
+    fn get_size(&self, fs: &FileSystem) -> usize {
+        match self.typ {
+            FileType::File(x) => x,
+            FileType::Folder(ref children) => {
+                children.iter().map(|x| fs.get(*x).get_size(fs)).sum()
+            }
+        }
+    }
+
+    fn is_folder(&self) -> bool {
+        matches!(self.typ, FileType::Folder(_))
+    }
+}
+
+fn generator(input: &str) -> Result<FileSystem> {
+    let mut fs = FileSystem::new();
+    let root_inode = fs.root_inode();
+    let mut dirstack = vec![root_inode];
+
+    for line in Text::new(input).lines() {
+        let line = line.trim();
+        let parent_inode = *dirstack.last().unwrap();
+        match line.as_str() {
+            "$ cd /" => dirstack.truncate(1),
+            "$ cd .." => {
+                if dirstack.len() == 1 {
+                    return Err(line.error("cannot leave the root folder"));
+                }
+                dirstack.pop();
+            }
+            "$ ls" => {
+                // Nothing to do here
+            }
+            x if x.starts_with("$ cd ") => {
+                let [folder_name] = line.scan("$ cd {}")?;
+                let next_folder = fs
+                    .get(parent_inode)
+                    .get_child_inode(&fs, folder_name.as_str())
+                    .ok_or_else(|| folder_name.error("no folder by that name here"))?;
+                if !fs.get(next_folder).is_folder() {
+                    return Err(
+                        folder_name.error(format!("{} is not a folder", folder_name.as_str()))
+                    );
+                }
+                dirstack.push(next_folder);
+            }
+            x if x.starts_with("dir ") => {
+                let [folder_name] = line.scan("dir {}")?;
+                fs.add_folder(parent_inode, folder_name.as_str());
+            }
+            x if x.starts_with(|c: char| c.is_ascii_digit()) => {
+                let [file_size, file_name] = line.scan("{} {}")?;
+                fs.add_file(parent_inode, file_name.as_str(), file_size.number()?);
+            }
+            _ => return Err(line.error("unrecognized statement")),
+        }
+    }
+
+    Ok(fs)
+}
+
+fn part1(fs: &FileSystem) -> usize {
+    let folders: Vec<&File> = fs.files.iter().filter(|x| x.is_folder()).collect();
+    folders
+        .iter()
+        .map(|f| f.get_size(fs))
+        .filter(|&size| size <= 100_000)
+        .sum()
+}
+
+fn part2(fs: &FileSystem) -> usize {
+    let total_space = 70_000_000;
+    let used_space = fs.files.first().unwrap().get_size(fs);
+    let free_space = total_space - used_space;
+    let to_free = 30_000_000 - free_space;
+
+    let folders: Vec<_> = fs.files.iter().filter(|f| f.is_folder()).collect();
+    folders
+        .into_iter()
+        .map(|f| f.get_size(fs))
+        .filter(|&size| size >= to_free)
+        .fold(usize::MAX, std::cmp::min)
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+crate::examples! {
+    INPUT_TEXT => part1: 95437, part2: 24933642;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cd_into_file() {
+        let error = generator("123 a\n$ cd a\ndir b").err().unwrap();
+        assert_eq!(error.message, "a is not a folder");
+        assert_eq!(error.position.unwrap().line, 2);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::Result;
+use crate::geometry::Direction;
+use crate::grid::Grid;
+use crate::registry::Day;
+
+fn generator(input: &str) -> Result<Grid<usize>> {
+    Grid::parse(input, |c| c.to_digit(10).map(|x| x as usize))
+}
+
+fn part1(input: &Grid<usize>) -> usize {
+    input
+        .positions()
+        .filter(|&(row, col)| {
+            // A tree can be seen from the outside if every tree between it
+            // and some edge is shorter.
+            let height = input[(row, col)];
+            Direction::ALL
+                .into_iter()
+                .any(|d| input.walk(row, col, d).all(|&x| x < height))
+        })
+        .count()
+}
+
+fn viewing_distance<'a>(it: impl Iterator<Item = &'a usize>, height: usize) -> usize {
+    let mut distance = 0;
+    for &x in it {
+        distance += 1;
+        if x >= height {
+            break;
+        }
+    }
+    distance
+}
+
+fn visibility_score(input: &Grid<usize>, row: usize, col: usize) -> usize {
+    let height = input[(row, col)];
+    Direction::ALL
+        .into_iter()
+        .map(|d| viewing_distance(input.walk(row, col, d), height))
+        .product()
+}
+
+fn part2(input: &Grid<usize>) -> usize {
+    input
+        .positions()
+        .map(|(row, col)| visibility_score(input, row, col))
+        .max()
+        .unwrap()
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "30373
+25512
+65332
+33549
+35390";
+
+crate::examples! {
+    INPUT_TEXT => part1: 21, part2: 8;
+}
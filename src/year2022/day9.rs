@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use crate::error::Result;
+use crate::geometry::{Direction, Point};
+use crate::parse::Text;
+use crate::registry::Day;
+
+struct Instruction {
+    direction: Direction,
+    magnitude: u32,
+}
+
+fn generator(input: &str) -> Result<Vec<Instruction>> {
+    Text::new(input)
+        .lines()
+        .map(|line| {
+            let [direction, magnitude] = line.trim().scan("{} {}")?;
+            let mut chars = direction.as_str().chars();
+            let direction = match (chars.next(), chars.next()) {
+                (Some(c), None) => Direction::from_char(c),
+                _ => None,
+            }
+            .ok_or_else(|| direction.error("expected U, D, L or R"))?;
+            Ok(Instruction {
+                direction,
+                magnitude: magnitude.number()?,
+            })
+        })
+        .collect()
+}
+
+fn new_tail(head: Point, tail: Point) -> Point {
+    if head.chebyshev(tail) <= 1 {
+        // no adjustment needed.
+        return tail;
+    }
+
+    // Too far away, the tail takes one step towards the head, diagonally
+    // if they are not in the same row or column.
+    tail + (head - tail).signum()
+}
+
+fn part1(input: &[Instruction]) -> usize {
+    let mut positions = HashSet::new();
+    let mut head = Point::ORIGIN;
+    let mut tail = head;
+    positions.insert(tail);
+
+    for instruction in input {
+        for _ in 0..instruction.magnitude {
+            head += instruction.direction.vector();
+            tail = new_tail(head, tail);
+            positions.insert(tail);
+        }
+    }
+
+    positions.len()
+}
+
+fn part2(input: &[Instruction]) -> usize {
+    let mut positions = HashSet::new();
+    let mut knots = [Point::ORIGIN; 10];
+    for instruction in input {
+        for _ in 0..instruction.magnitude {
+            // move head
+            knots[0] += instruction.direction.vector();
+
+            // move knots
+            for idx in 1..knots.len() {
+                knots[idx] = new_tail(knots[idx - 1], knots[idx]);
+            }
+
+            positions.insert(*knots.last().unwrap());
+        }
+    }
+
+    positions.len()
+}
+
+pub fn register(day: &mut Day) {
+    day.part1(generator, part1)
+        .part2(generator, part2)
+        .examples(examples());
+}
+
+const INPUT_TEXT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+const MORE_INPUT_TEXT: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+crate::examples! {
+    INPUT_TEXT => part1: 13, part2: 1;
+    MORE_INPUT_TEXT => part2: 36;
+}
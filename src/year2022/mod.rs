@@ -0,0 +1,25 @@
+use crate::registry::Registry;
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub fn register(registry: &mut Registry) {
+    day1::register(&mut registry.day(2022, 1));
+    day2::register(&mut registry.day(2022, 2));
+    day3::register(&mut registry.day(2022, 3));
+    day4::register(&mut registry.day(2022, 4));
+    day5::register(&mut registry.day(2022, 5));
+    day6::register(&mut registry.day(2022, 6));
+    day7::register(&mut registry.day(2022, 7));
+    day8::register(&mut registry.day(2022, 8));
+    day9::register(&mut registry.day(2022, 9));
+    day10::register(&mut registry.day(2022, 10));
+}